@@ -6,12 +6,15 @@ use sp_core::{sr25519, Pair, Public};
 use sp_finality_grandpa::AuthorityId as GrandpaId;
 use sp_runtime::traits::{IdentifyAccount, Verify};
 
-use opensquare_primitives::CurrencyId;
+use opensquare_primitives::{BountyCategory, CurrencyId, BLOCKS_PER_DAY};
 use opensquare_runtime::{self, AccountId, Signature};
 use opensquare_runtime::{
-    AuraConfig, BalancesConfig, GenesisConfig, GrandpaConfig, OracleConfig, OsBountiesConfig,
-    OsSystemConfig, SudoConfig, SystemConfig, TokensConfig, WASM_BINARY,
+    AuraConfig, BalancesConfig, GenesisConfig, GrandpaConfig, OracleConfig, OsBadgesConfig,
+    OsBountiesConfig, OsReputationConfig, OsSystemConfig, SudoConfig, SystemConfig, TokensConfig,
+    WASM_BINARY,
 };
+use ospallet_badges::{BadgeCondition, BadgeRule};
+use ospallet_reputation::{Behavior, BountyRemarkCollaborationResult, Role, ScoreTable};
 
 // The URL for the telemetry server.
 // const STAGING_TELEMETRY_URL: &str = "wss://telemetry.polkadot.io/submit/";
@@ -199,5 +202,38 @@ fn testnet_genesis(
             ],
             dummy: 0u32,
         }),
+        ospallet_reputation: Some(OsReputationConfig {
+            hunter_score_table: ScoreTable::HUNTER,
+            funder_score_table: ScoreTable::FUNDER,
+            decay_half_life: None,
+            // 100 USDT
+            unit_value: 10_000_000_000_000_000,
+            max_value_multiplier: 8,
+            low_reputation_threshold: 10,
+            appeal_period: 7 * BLOCKS_PER_DAY,
+            reversal_penalty: -5,
+            extension_penalty: -1,
+            endorsement_unit: 10,
+            max_endorsement_weight: 5,
+        }),
+        ospallet_badges: Some(OsBadgesConfig {
+            rules: vec![
+                BadgeRule {
+                    name: b"10 development bounties resolved".to_vec(),
+                    condition: BadgeCondition::BountiesResolved(
+                        Some(BountyCategory::Development),
+                        10,
+                    ),
+                },
+                BadgeRule {
+                    name: b"First Perfect remark".to_vec(),
+                    condition: BadgeCondition::BehaviorReceived(
+                        Role::Hunter,
+                        Behavior::BountyRemark(BountyRemarkCollaborationResult::Perfect),
+                        1,
+                    ),
+                },
+            ],
+        }),
     }
 }
@@ -1,6 +1,8 @@
 use sc_cli::{ChainSpec, Role, RuntimeVersion, SubstrateCli};
 use sc_service::PartialComponents;
 
+use opensquare_primitives::Block;
+
 use crate::chain_spec;
 use crate::cli::{Cli, Subcommand};
 use crate::service;
@@ -154,5 +156,16 @@ pub fn run() -> sc_cli::Result<()> {
                 Ok((cmd.run(client, backend), task_manager))
             })
         }
+        Some(Subcommand::Benchmark(cmd)) => {
+            if cfg!(feature = "runtime-benchmarks") {
+                let runner = cli.create_runner(cmd)?;
+
+                runner.sync_run(|config| cmd.run::<Block, service::Executor>(config))
+            } else {
+                Err("Benchmarking wasn't enabled when building the node. \
+                    You can enable it with `--features runtime-benchmarks`."
+                    .into())
+            }
+        }
     }
 }
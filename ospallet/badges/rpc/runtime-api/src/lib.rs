@@ -0,0 +1,22 @@
+//! Runtime API definition for the badges module.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+#![allow(clippy::too_many_arguments, clippy::unnecessary_mut_passed)]
+
+use codec::Codec;
+use sp_std::prelude::*;
+
+use ospallet_badges::{BadgeId, BadgeRule};
+
+sp_api::decl_runtime_apis! {
+    pub trait BadgesApi<AccountId, BlockNumber> where
+        AccountId: Codec,
+        BlockNumber: Codec,
+    {
+        /// Get the badges earned by an account with the block earned.
+        fn badges_of(who: AccountId) -> Vec<(BadgeId, BlockNumber)>;
+
+        /// Get the badge rules in effect.
+        fn badge_rules() -> Vec<(BadgeId, BadgeRule)>;
+    }
+}
@@ -0,0 +1,97 @@
+//! Benchmarks for ospallet_badges.
+
+#![cfg(feature = "runtime-benchmarks")]
+
+use frame_benchmarking::{account, benchmarks};
+use frame_support::traits::{EnsureOrigin, UnfilteredDispatchable};
+use sp_std::{prelude::*, vec};
+
+use ospallet_bounties::BountyCategory;
+use ospallet_reputation::{Behavior, BountyContext, OnBehavior, Role};
+
+use crate::{BadgeCondition, Call, Module, Trait};
+
+/// Same as the default `MaxRules`.
+const MAX_RULES: u32 = 32;
+const SEED: u32 = 0;
+const ALL_CATEGORIES: [BountyCategory; 3] = [
+    BountyCategory::Development,
+    BountyCategory::Design,
+    BountyCategory::Document,
+];
+
+fn add_rules<T: Trait>(count: u32, condition: BadgeCondition) {
+    for _ in 0..count {
+        let origin = T::CouncilOrigin::successful_origin();
+        Call::<T>::add_badge_rule(vec![b'x'; 64], condition.clone())
+            .dispatch_bypass_filter(origin)
+            .expect("add badge rule works");
+    }
+}
+
+fn unmet_rule() -> BadgeCondition {
+    BadgeCondition::BountiesResolved(Some(BountyCategory::Development), 10)
+}
+
+benchmarks! {
+    _ { }
+
+    add_badge_rule {
+        let r in 0 .. MAX_RULES - 1;
+        add_rules::<T>(r, unmet_rule());
+        let origin = T::CouncilOrigin::successful_origin();
+        let call = Call::<T>::add_badge_rule(
+            vec![b'x'; 64],
+            BadgeCondition::BountiesResolved(None, 10),
+        );
+    }: { call.dispatch_bypass_filter(origin)? }
+    verify {
+        assert_eq!(Module::<T>::rules().len() as u32, r + 1);
+    }
+
+    // the last rule is found after the others
+    remove_badge_rule {
+        let r in 1 .. MAX_RULES;
+        add_rules::<T>(r, unmet_rule());
+        let origin = T::CouncilOrigin::successful_origin();
+        let call = Call::<T>::remove_badge_rule(r - 1);
+    }: { call.dispatch_bypass_filter(origin)? }
+    verify {
+        assert_eq!(Module::<T>::rules().len() as u32, r - 1);
+    }
+
+    // worst case: every rule is met and awarded, counting the bounties of all categories
+    note_resolved {
+        let r in 0 .. MAX_RULES;
+        add_rules::<T>(r, BadgeCondition::BountiesResolved(None, 3));
+        let hunter: T::AccountId = account("hunter", 0, SEED);
+        for category in ALL_CATEGORIES.iter() {
+            ospallet_bounties::ResolvedCount::<T>::insert(&hunter, category, 1);
+        }
+    }: { Module::<T>::note_resolved(&hunter) }
+    verify {
+        assert_eq!(Module::<T>::badges_of(&hunter).len() as u32, r);
+    }
+
+    on_behavior {
+        let r in 0 .. MAX_RULES;
+        add_rules::<T>(r, BadgeCondition::BehaviorReceived(Role::Hunter, Behavior::BountyTipped, 1));
+        let hunter: T::AccountId = account("hunter", 0, SEED);
+        let context = BountyContext {
+            bounty_id: Default::default(),
+            category: BountyCategory::Development,
+            counterparty: account("funder", 0, SEED),
+            value: 0,
+        };
+    }: {
+        <Module<T> as OnBehavior<T::AccountId>>::on_behavior(
+            &hunter,
+            Role::Hunter,
+            &Behavior::BountyTipped,
+            &context,
+        )
+    }
+    verify {
+        assert_eq!(Module::<T>::badges_of(&hunter).len() as u32, r);
+    }
+}
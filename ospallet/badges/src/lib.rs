@@ -0,0 +1,462 @@
+#![cfg_attr(not(feature = "std"), no_std)]
+
+use frame_support::{
+    decl_error, decl_event, decl_module, decl_storage, dispatch::DispatchResult, ensure,
+    storage::IterableStorageDoubleMap, traits::EnsureOrigin, weights::Weight,
+};
+use sp_std::prelude::*;
+
+use ospallet_bounties::{BountyOf, BountyResolved};
+use ospallet_reputation::{Behavior, BountyContext, OnBehavior, Role};
+
+pub use crate::types::{BadgeCondition, BadgeId, BadgeRule};
+
+#[cfg(feature = "runtime-benchmarks")]
+mod benchmarking;
+mod types;
+mod weights;
+
+pub use weights::WeightInfo;
+
+pub trait Trait: ospallet_bounties::Trait {
+    type Event: From<Event<Self>> + Into<<Self as frame_system::Trait>::Event>;
+
+    /// Weight information for extrinsics in this pallet.
+    type WeightInfo: WeightInfo;
+}
+
+decl_error! {
+    pub enum Error for Module<T: Trait> {
+        /// badge name is longer than `MaxBadgeNameLength`
+        NameTooLong,
+        /// beyond limit of `MaxRules`
+        TooManyRules,
+        /// the count of a condition should be larger than zero
+        InvalidCondition,
+        /// no badge rule with this id
+        RuleNotFound,
+    }
+}
+
+decl_event!(
+    pub enum Event<T> where
+        <T as frame_system::Trait>::AccountId,
+    {
+        BadgeRuleAdded(BadgeId),
+        BadgeRuleRemoved(BadgeId),
+        BadgeAwarded(AccountId, BadgeId),
+    }
+);
+
+decl_storage! {
+    trait Store for Module<T: Trait> as OsBadges {
+        /// Badge rules evaluated on each resolved bounty and behavior
+        pub Rules get(fn rules): Vec<(BadgeId, BadgeRule)>;
+        pub NextBadgeId get(fn next_badge_id): BadgeId;
+        pub MaxRules get(fn max_rules): u32 = 32;
+        pub MaxBadgeNameLength get(fn max_badge_name_length): u32 = 64;
+
+        /// Badges earned by an account with the block earned, kept after their rules are removed
+        pub Badges get(fn badges):
+            double_map hasher(blake2_128_concat) T::AccountId, hasher(twox_64_concat) BadgeId => Option<T::BlockNumber>;
+
+        /// Count of behaviors received by an account as a role
+        pub BehaviorCount get(fn behavior_count):
+            double_map hasher(blake2_128_concat) T::AccountId, hasher(blake2_128_concat) (Role, Behavior) => u32;
+    }
+        add_extra_genesis {
+            config(rules): Vec<BadgeRule>;
+            build(|config| {
+                let rules = config.rules
+                    .iter()
+                    .cloned()
+                    .enumerate()
+                    .map(|(id, rule)| (id as BadgeId, rule))
+                    .collect::<Vec<_>>();
+                NextBadgeId::put(rules.len() as BadgeId);
+                Rules::put(rules);
+            })
+        }
+}
+
+decl_module! {
+    pub struct Module<T: Trait> for enum Call where origin: T::Origin {
+        type Error = Error<T>;
+
+        fn deposit_event() = default;
+
+        /// add a badge rule, accounts meeting it earn the badge on their next resolved bounty or behavior
+        #[weight = <T as Trait>::WeightInfo::add_badge_rule(Self::max_rules())]
+        fn add_badge_rule(origin, name: Vec<u8>, condition: BadgeCondition) -> DispatchResult {
+            T::CouncilOrigin::ensure_origin(origin)?;
+            ensure!(
+                name.len() as u32 <= Self::max_badge_name_length(),
+                Error::<T>::NameTooLong
+            );
+            let count = match condition {
+                BadgeCondition::BountiesResolved(_, count) => count,
+                BadgeCondition::BehaviorReceived(_, _, count) => count,
+            };
+            ensure!(count > 0, Error::<T>::InvalidCondition);
+
+            let mut rules = Self::rules();
+            ensure!((rules.len() as u32) < Self::max_rules(), Error::<T>::TooManyRules);
+            let badge_id = Self::next_badge_id();
+            rules.push((badge_id, BadgeRule { name, condition }));
+            Rules::put(rules);
+            NextBadgeId::put(badge_id.saturating_add(1));
+
+            Self::deposit_event(RawEvent::BadgeRuleAdded(badge_id));
+            Ok(())
+        }
+
+        /// remove a badge rule, badges already earned are kept
+        #[weight = <T as Trait>::WeightInfo::remove_badge_rule(Self::max_rules())]
+        fn remove_badge_rule(origin, badge_id: BadgeId) -> DispatchResult {
+            T::CouncilOrigin::ensure_origin(origin)?;
+            let mut rules = Self::rules();
+            let index = rules
+                .iter()
+                .position(|(id, _)| *id == badge_id)
+                .ok_or(Error::<T>::RuleNotFound)?;
+            rules.remove(index);
+            Rules::put(rules);
+
+            Self::deposit_event(RawEvent::BadgeRuleRemoved(badge_id));
+            Ok(())
+        }
+    }
+}
+
+impl<T: Trait> Module<T> {
+    /// Badges earned by an account with the block earned.
+    pub fn badges_of(who: &T::AccountId) -> Vec<(BadgeId, T::BlockNumber)> {
+        Badges::<T>::iter_prefix(who).collect()
+    }
+
+    /// Award the badges whose rules are met by the account now.
+    fn award_badges(who: &T::AccountId) {
+        let now = frame_system::Module::<T>::block_number();
+        for (badge_id, rule) in Self::rules() {
+            if Badges::<T>::contains_key(who, badge_id) || !Self::meets(who, &rule.condition) {
+                continue;
+            }
+            Badges::<T>::insert(who, badge_id, now);
+            Self::deposit_event(RawEvent::BadgeAwarded(who.clone(), badge_id));
+        }
+    }
+
+    /// Award the badges met by the hunter of a resolved bounty, which is counted by `ospallet_bounties`.
+    fn note_resolved(hunter: &T::AccountId) {
+        Self::award_badges(hunter);
+    }
+
+    fn meets(who: &T::AccountId, condition: &BadgeCondition) -> bool {
+        match *condition {
+            BadgeCondition::BountiesResolved(Some(category), count) => {
+                ospallet_bounties::Module::<T>::resolved_count(who, category) >= count
+            }
+            BadgeCondition::BountiesResolved(None, count) => {
+                ospallet_bounties::ResolvedCount::<T>::iter_prefix(who)
+                    .fold(0u32, |total, (_, resolved)| total.saturating_add(resolved))
+                    >= count
+            }
+            BadgeCondition::BehaviorReceived(role, behavior, count) => {
+                Self::behavior_count(who, (role, behavior)) >= count
+            }
+        }
+    }
+}
+
+impl<T: Trait> BountyResolved<T> for Module<T> {
+    fn after_bounty_resolved(_bounty: &BountyOf<T>, hunter: &T::AccountId) {
+        Self::note_resolved(hunter);
+    }
+
+    fn after_bounty_resolved_weight() -> Weight {
+        <T as Trait>::WeightInfo::note_resolved(Self::max_rules())
+    }
+}
+
+impl<T: Trait> OnBehavior<T::AccountId> for Module<T> {
+    fn on_behavior(
+        target: &T::AccountId,
+        role: Role,
+        behavior: &Behavior,
+        _context: &BountyContext<T::AccountId>,
+    ) {
+        BehaviorCount::<T>::mutate(target, (role, *behavior), |count| {
+            *count = count.saturating_add(1)
+        });
+        Self::award_badges(target);
+    }
+
+    fn on_behavior_weight() -> Weight {
+        <T as Trait>::WeightInfo::on_behavior(Self::max_rules())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use frame_support::{impl_outer_origin, parameter_types, weights::Weight};
+    use frame_system::{EnsureRoot, RawOrigin};
+    use sp_core::H256;
+    use sp_io::TestExternalities;
+    use sp_runtime::{
+        testing::Header,
+        traits::{BlakeTwo256, IdentityLookup},
+        Perbill, Percent,
+    };
+
+    use ospallet_bounties::BountyCategory;
+    use ospallet_reputation::BountyRemarkCollaborationResult;
+
+    pub fn new_test_ext() -> TestExternalities {
+        let t = frame_system::GenesisConfig::default()
+            .build_storage::<Test>()
+            .unwrap();
+        TestExternalities::new(t)
+    }
+
+    impl_outer_origin! {
+        pub enum Origin for Test where system = frame_system {}
+    }
+
+    #[derive(Clone, Eq, PartialEq)]
+    pub struct Test;
+    parameter_types! {
+        pub const BlockHashCount: u64 = 250;
+        pub const MaximumBlockWeight: Weight = 1024;
+        pub const MaximumBlockLength: u32 = 2 * 1024;
+        pub const AvailableBlockRatio: Perbill = Perbill::one();
+        pub const ExistentialDeposit: u64 = 1;
+        pub const MaxLocks: u32 = 50;
+        pub const AppealDeposit: u64 = 10;
+        pub const CouncilAccount: u64 = 100;
+        pub const CouncilFee: Percent = Percent::from_percent(10);
+        pub const GetNativeCurrencyId: u32 = 0;
+        pub const ReapDeposit: u64 = 1;
+        pub const TipMining: bool = true;
+        pub const TipReputation: bool = true;
+    }
+    impl frame_system::Trait for Test {
+        type BaseCallFilter = ();
+        type Origin = Origin;
+        type Index = u64;
+        type BlockNumber = u64;
+        type Call = ();
+        type Hash = H256;
+        type Hashing = BlakeTwo256;
+        type AccountId = u64;
+        type Lookup = IdentityLookup<Self::AccountId>;
+        type Header = Header;
+        type Event = ();
+        type BlockHashCount = BlockHashCount;
+        type MaximumBlockWeight = MaximumBlockWeight;
+        type DbWeight = ();
+        type BlockExecutionWeight = ();
+        type ExtrinsicBaseWeight = ();
+        type MaximumExtrinsicWeight = MaximumBlockWeight;
+        type AvailableBlockRatio = AvailableBlockRatio;
+        type MaximumBlockLength = MaximumBlockLength;
+        type Version = ();
+        type PalletInfo = ();
+        type AccountData = pallet_balances::AccountData<u64>;
+        type OnNewAccount = ();
+        type OnKilledAccount = ();
+        type SystemWeightInfo = ();
+    }
+
+    impl pallet_balances::Trait for Test {
+        type MaxLocks = MaxLocks;
+        type Balance = u64;
+        type Event = ();
+        type DustRemoval = ();
+        type ExistentialDeposit = ExistentialDeposit;
+        type AccountStore = System;
+        type WeightInfo = ();
+    }
+
+    impl orml_tokens::Trait for Test {
+        type Event = ();
+        type Balance = u64;
+        type Amount = i64;
+        type CurrencyId = u32;
+        type OnReceived = ();
+        type WeightInfo = ();
+    }
+
+    impl ospallet_reputation::Trait for Test {
+        type Event = ();
+        type Currency = Balances;
+        type CouncilOrigin = EnsureRoot<u64>;
+        type AppealDeposit = AppealDeposit;
+        type WeighReputation = ();
+        type OnBehavior = OsBadges;
+        type WeightInfo = ();
+    }
+
+    impl ospallet_mining::Trait for Test {
+        type Event = ();
+        type Currency = Balances;
+        type WeightInfo = ();
+    }
+
+    impl ospallet_bounties::Trait for Test {
+        type Event = ();
+        type Currency = Tokens;
+        type CouncilOrigin = EnsureRoot<u64>;
+        type CouncilAccount = CouncilAccount;
+        type CouncilFee = CouncilFee;
+        type DetermineBountyId = ospallet_bounties::SequentialBountyIdDeterminer<Test>;
+        type BountyResolved = OsBadges;
+        type ReputationBuilder = Reputation;
+        type MiningPowerBuilder = Mining;
+        type GetNativeCurrencyId = GetNativeCurrencyId;
+        type ReapDeposit = ReapDeposit;
+        type TipMining = TipMining;
+        type TipReputation = TipReputation;
+        type WeightInfo = ();
+    }
+
+    impl Trait for Test {
+        type Event = ();
+        type WeightInfo = ();
+    }
+
+    type System = frame_system::Module<Test>;
+    type Balances = pallet_balances::Module<Test>;
+    type Tokens = orml_tokens::Module<Test>;
+    type Reputation = ospallet_reputation::Module<Test>;
+    type Mining = ospallet_mining::Module<Test>;
+    type OsBadges = Module<Test>;
+
+    fn add_rule(condition: BadgeCondition) -> DispatchResult {
+        OsBadges::add_badge_rule(RawOrigin::Root.into(), b"badge".to_vec(), condition)
+    }
+
+    /// Count a resolved bounty like `ospallet_bounties` does before calling the handlers.
+    fn resolve(hunter: u64, category: BountyCategory) {
+        ospallet_bounties::ResolvedCount::<Test>::mutate(hunter, category, |count| *count += 1);
+        OsBadges::note_resolved(&hunter);
+    }
+
+    fn receive(who: u64, role: Role, behavior: Behavior) {
+        let context = BountyContext {
+            bounty_id: Default::default(),
+            category: BountyCategory::Development,
+            counterparty: 2,
+            value: 0,
+        };
+        OsBadges::on_behavior(&who, role, &behavior, &context);
+    }
+
+    #[test]
+    fn badge_rules_are_checked() {
+        new_test_ext().execute_with(|| {
+            let condition = BadgeCondition::BountiesResolved(None, 1);
+            assert!(OsBadges::add_badge_rule(
+                RawOrigin::Signed(1).into(),
+                b"badge".to_vec(),
+                condition.clone()
+            )
+            .is_err());
+            assert_eq!(
+                OsBadges::add_badge_rule(RawOrigin::Root.into(), vec![b'x'; 65], condition.clone()),
+                Err(Error::<Test>::NameTooLong.into())
+            );
+            assert_eq!(
+                add_rule(BadgeCondition::BountiesResolved(None, 0)),
+                Err(Error::<Test>::InvalidCondition.into())
+            );
+
+            MaxRules::put(2);
+            assert!(add_rule(condition.clone()).is_ok());
+            assert!(add_rule(condition.clone()).is_ok());
+            assert_eq!(
+                add_rule(condition.clone()),
+                Err(Error::<Test>::TooManyRules.into())
+            );
+
+            // ids are not reused after a rule is removed
+            assert!(OsBadges::remove_badge_rule(RawOrigin::Root.into(), 0).is_ok());
+            assert_eq!(
+                OsBadges::remove_badge_rule(RawOrigin::Root.into(), 0),
+                Err(Error::<Test>::RuleNotFound.into())
+            );
+            assert!(add_rule(condition).is_ok());
+            assert_eq!(
+                OsBadges::rules()
+                    .into_iter()
+                    .map(|(id, _)| id)
+                    .collect::<Vec<_>>(),
+                vec![1, 2]
+            );
+        });
+    }
+
+    #[test]
+    fn resolved_bounties_award_badges() {
+        new_test_ext().execute_with(|| {
+            System::set_block_number(1);
+            add_rule(BadgeCondition::BountiesResolved(
+                Some(BountyCategory::Design),
+                2,
+            ))
+            .unwrap();
+            add_rule(BadgeCondition::BountiesResolved(None, 3)).unwrap();
+
+            resolve(1, BountyCategory::Design);
+            resolve(1, BountyCategory::Document);
+            assert!(OsBadges::badges_of(&1).is_empty());
+
+            // counted in all categories
+            System::set_block_number(2);
+            resolve(1, BountyCategory::Development);
+            assert_eq!(OsBadges::badges_of(&1), vec![(1, 2)]);
+
+            System::set_block_number(3);
+            resolve(1, BountyCategory::Design);
+            let mut badges = OsBadges::badges_of(&1);
+            badges.sort();
+            assert_eq!(badges, vec![(0, 3), (1, 2)]);
+            assert!(OsBadges::badges_of(&2).is_empty());
+        });
+    }
+
+    #[test]
+    fn behaviors_award_badges() {
+        new_test_ext().execute_with(|| {
+            let perfect = Behavior::BountyRemark(BountyRemarkCollaborationResult::Perfect);
+            add_rule(BadgeCondition::BehaviorReceived(Role::Hunter, perfect, 2)).unwrap();
+
+            receive(1, Role::Hunter, perfect);
+            receive(1, Role::Funder, perfect);
+            receive(1, Role::Hunter, Behavior::BountyTipped);
+            assert!(OsBadges::badges_of(&1).is_empty());
+            assert_eq!(OsBadges::behavior_count(1, (Role::Hunter, perfect)), 1);
+
+            receive(1, Role::Hunter, perfect);
+            assert_eq!(OsBadges::badges_of(&1), vec![(0, 0)]);
+
+            // earned badges are kept after their rules are removed
+            assert!(OsBadges::remove_badge_rule(RawOrigin::Root.into(), 0).is_ok());
+            receive(1, Role::Hunter, perfect);
+            assert_eq!(OsBadges::badges_of(&1), vec![(0, 0)]);
+        });
+    }
+
+    #[test]
+    fn rules_added_later_are_met_on_next_event() {
+        new_test_ext().execute_with(|| {
+            resolve(1, BountyCategory::Design);
+            add_rule(BadgeCondition::BountiesResolved(None, 1)).unwrap();
+            assert!(OsBadges::badges_of(&1).is_empty());
+
+            receive(1, Role::Hunter, Behavior::BountyTipped);
+            assert_eq!(OsBadges::badges_of(&1), vec![(0, 0)]);
+        });
+    }
+}
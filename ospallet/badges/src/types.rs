@@ -0,0 +1,28 @@
+use codec::{Decode, Encode};
+#[cfg(feature = "std")]
+use serde::{Deserialize, Serialize};
+use sp_std::prelude::*;
+
+use sp_runtime::RuntimeDebug;
+
+use ospallet_bounties::BountyCategory;
+use ospallet_reputation::{Behavior, Role};
+
+pub type BadgeId = u32;
+
+/// What an account should have done to earn a badge.
+#[derive(PartialEq, Eq, Clone, Encode, Decode, RuntimeDebug)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+pub enum BadgeCondition {
+    /// Resolved the number of bounties as the hunter, in the category or in any category if it's none
+    BountiesResolved(Option<BountyCategory>, u32),
+    /// Received the behavior the number of times as the role
+    BehaviorReceived(Role, Behavior, u32),
+}
+
+#[derive(PartialEq, Eq, Clone, Encode, Decode, RuntimeDebug)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+pub struct BadgeRule {
+    pub name: Vec<u8>,
+    pub condition: BadgeCondition,
+}
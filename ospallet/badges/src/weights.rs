@@ -0,0 +1,48 @@
+//! Weights for ospallet_badges.
+//!
+//! `()` implements `WeightInfo` with the weights used in tests and chains without their own
+//! benchmark results. Runtimes should use the weights generated by the `benchmark` subcommand.
+//!
+//! Components:
+//! - `r`: badge rules, bounded by `MaxRules`
+
+#![allow(unused_parens)]
+
+use frame_support::weights::{constants::RocksDbWeight as DbWeight, Weight};
+
+pub trait WeightInfo {
+    fn add_badge_rule(r: u32) -> Weight;
+    fn remove_badge_rule(r: u32) -> Weight;
+    fn note_resolved(r: u32) -> Weight;
+    fn on_behavior(r: u32) -> Weight;
+}
+
+impl WeightInfo for () {
+    fn add_badge_rule(r: u32) -> Weight {
+        (30_000_000 as Weight)
+            .saturating_add((300_000 as Weight).saturating_mul(r as Weight))
+            .saturating_add(DbWeight::get().reads(4 as Weight))
+            .saturating_add(DbWeight::get().writes(2 as Weight))
+    }
+    fn remove_badge_rule(r: u32) -> Weight {
+        (30_000_000 as Weight)
+            .saturating_add((300_000 as Weight).saturating_mul(r as Weight))
+            .saturating_add(DbWeight::get().reads(1 as Weight))
+            .saturating_add(DbWeight::get().writes(1 as Weight))
+    }
+    fn note_resolved(r: u32) -> Weight {
+        (15_000_000 as Weight)
+            .saturating_add((4_000_000 as Weight).saturating_mul(r as Weight))
+            .saturating_add(DbWeight::get().reads(1 as Weight))
+            .saturating_add(DbWeight::get().reads((4 as Weight).saturating_mul(r as Weight)))
+            .saturating_add(DbWeight::get().writes((1 as Weight).saturating_mul(r as Weight)))
+    }
+    fn on_behavior(r: u32) -> Weight {
+        (15_000_000 as Weight)
+            .saturating_add((3_000_000 as Weight).saturating_mul(r as Weight))
+            .saturating_add(DbWeight::get().reads(2 as Weight))
+            .saturating_add(DbWeight::get().reads((2 as Weight).saturating_mul(r as Weight)))
+            .saturating_add(DbWeight::get().writes(1 as Weight))
+            .saturating_add(DbWeight::get().writes((1 as Weight).saturating_mul(r as Weight)))
+    }
+}
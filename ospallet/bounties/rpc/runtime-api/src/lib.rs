@@ -0,0 +1,19 @@
+//! Runtime API definition for the bounties module.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+#![allow(clippy::too_many_arguments, clippy::unnecessary_mut_passed)]
+
+use sp_std::prelude::*;
+
+use opensquare_primitives::BountyId;
+use ospallet_bounties::{BountyCategory, BountyState};
+
+sp_api::decl_runtime_apis! {
+    pub trait BountiesApi {
+        /// Get bounty ids in the given state, paginated by `offset` and `limit`.
+        fn bounties_by_state(state: BountyState, offset: u32, limit: u32) -> Vec<BountyId>;
+
+        /// Get bounty ids in the given category, paginated by `offset` and `limit`.
+        fn bounties_by_category(category: BountyCategory, offset: u32, limit: u32) -> Vec<BountyId>;
+    }
+}
@@ -0,0 +1,442 @@
+//! Benchmarks for ospallet_bounties.
+
+#![cfg(feature = "runtime-benchmarks")]
+
+use frame_benchmarking::{account, benchmarks};
+use frame_support::traits::{EnsureOrigin, Get, UnfilteredDispatchable};
+use frame_system::RawOrigin;
+use sp_runtime::traits::{SaturatedConversion, Saturating, StaticLookup};
+use sp_std::{prelude::*, vec};
+
+use opensquare_primitives::BountyId;
+use orml_traits::MultiCurrency;
+use ospallet_reputation::BountyRemarkCollaborationResult;
+
+use crate::types::{Bounty, BountyCategory, BountyMetaData, BountyOf, CloseReason, DeclineReason};
+use crate::{BalanceOf, Call, Module, Trait};
+
+const SEED: u32 = 0;
+/// Same as the default `ApplicantsLimit`.
+const MAX_APPLICANTS: u32 = 64;
+/// Same as the default `MaxHoldingBounties`.
+const MAX_HOLDING: u32 = 10;
+/// Same as the default `MaxPrerequisites`.
+const MAX_PREREQUISITES: u32 = 8;
+/// Same as the default `MaxDependents`.
+const MAX_DEPENDENTS: u32 = 32;
+
+fn funded_account<T: Trait>(name: &'static str, index: u32) -> T::AccountId {
+    let who: T::AccountId = account(name, index, SEED);
+    let amount: BalanceOf<T> = 1_000_000_000_000_000_000u128.saturated_into();
+    T::Currency::deposit(T::GetNativeCurrencyId::get(), &who, amount)
+        .expect("deposit to a new account works");
+    who
+}
+
+fn new_bounty<T: Trait>(funder: &T::AccountId) -> BountyOf<T> {
+    Bounty::V2(BountyMetaData {
+        owner: funder.clone(),
+        currency_id: T::GetNativeCurrencyId::get(),
+        payment: 1_000_000_000_000u128.saturated_into(),
+        category: BountyCategory::Development,
+        title: vec![b'x'; 64],
+        digest: Default::default(),
+    })
+}
+
+fn create<T: Trait>(funder: &T::AccountId) -> BountyId {
+    Module::<T>::create_bounty_impl(funder.clone(), new_bounty::<T>(funder))
+        .expect("funder could pay the bounty")
+}
+
+fn accepted<T: Trait>(funder: &T::AccountId) -> BountyId {
+    let bounty_id = create::<T>(funder);
+    Module::<T>::examine_bounty_impl(bounty_id, true).expect("bounty is applying");
+    bounty_id
+}
+
+/// Create `count` bounties depending on `bounty_id`.
+fn add_dependents<T: Trait>(funder: &T::AccountId, bounty_id: BountyId, count: u32) {
+    for _ in 0..count {
+        let dependent = create::<T>(funder);
+        Module::<T>::set_prerequisites_impl(dependent, funder.clone(), vec![bounty_id])
+            .expect("dependent is applying");
+    }
+}
+
+fn hunt<T: Trait>(bounty_id: BountyId, name: &'static str, count: u32) -> Vec<T::AccountId> {
+    (0..count)
+        .map(|i| {
+            let hunter: T::AccountId = account(name, i, SEED);
+            Module::<T>::hunt_bounty_impl(bounty_id, hunter.clone()).expect("bounty is accepted");
+            hunter
+        })
+        .collect()
+}
+
+fn assigned<T: Trait>(funder: &T::AccountId) -> (BountyId, T::AccountId) {
+    let bounty_id = accepted::<T>(funder);
+    let hunter = hunt::<T>(bounty_id, "hunter", 1).remove(0);
+    Module::<T>::assign_bounty_impl(bounty_id, funder.clone(), hunter.clone())
+        .expect("hunter is hunting the bounty");
+    (bounty_id, hunter)
+}
+
+fn submitted<T: Trait>(funder: &T::AccountId) -> (BountyId, T::AccountId) {
+    let (bounty_id, hunter) = assigned::<T>(funder);
+    Module::<T>::submit_bounty_impl(bounty_id, hunter.clone()).expect("bounty is assigned");
+    (bounty_id, hunter)
+}
+
+const SALT: [u8; 32] = [7; 32];
+
+fn commitment<T: Trait>(bounty_id: BountyId, who: &T::AccountId) -> T::Hash {
+    Module::<T>::remark_commitment(bounty_id, who, BountyRemarkCollaborationResult::Good, &SALT)
+}
+
+/// Resolved bounty with remarks of both sides committed.
+fn remarks_committed<T: Trait>(funder: &T::AccountId) -> (BountyId, T::AccountId) {
+    let (bounty_id, hunter) = submitted::<T>(funder);
+    Module::<T>::resolve_bounty_and_remark_impl(
+        bounty_id,
+        funder.clone(),
+        commitment::<T>(bounty_id, funder),
+        None,
+    )
+    .expect("bounty is submitted");
+    Module::<T>::remark_bounty_funder_impl(
+        bounty_id,
+        hunter.clone(),
+        commitment::<T>(bounty_id, &hunter),
+    )
+    .expect("bounty is resolved");
+    (bounty_id, hunter)
+}
+
+fn new_template<T: Trait>(owner: &T::AccountId) {
+    Module::<T>::create_template_impl(
+        owner.clone(),
+        T::GetNativeCurrencyId::get(),
+        1_000_000_000_000u128.saturated_into(),
+        BountyCategory::Development,
+        vec![b'x'; 64],
+        Default::default(),
+    )
+    .expect("create template works");
+}
+
+fn advance_blocks<T: Trait>(blocks: T::BlockNumber) {
+    let now = frame_system::Module::<T>::block_number();
+    frame_system::Module::<T>::set_block_number(now.saturating_add(blocks));
+}
+
+benchmarks! {
+    _ { }
+
+    create_bounty {
+        let funder = funded_account::<T>("funder", 0);
+        let bounty = new_bounty::<T>(&funder);
+    }: _(RawOrigin::Signed(funder), bounty)
+    verify {
+        assert_eq!(Module::<T>::bounty_count(), 1);
+    }
+
+    close_bounty {
+        let a in 0 .. MAX_APPLICANTS;
+        let d in 0 .. MAX_DEPENDENTS;
+        let funder = funded_account::<T>("funder", 0);
+        let bounty_id = accepted::<T>(&funder);
+        hunt::<T>(bounty_id, "applicant", a);
+        add_dependents::<T>(&funder, bounty_id, d);
+    }: _(RawOrigin::Signed(funder), bounty_id)
+
+    assign_bounty {
+        let funder = funded_account::<T>("funder", 0);
+        let bounty_id = accepted::<T>(&funder);
+        Module::<T>::set_streaming_impl(bounty_id, funder.clone(), true)?;
+        let mut hunters = hunt::<T>(bounty_id, "hunter", 2);
+        let hunter = hunters.remove(1);
+        let hunter_lookup = T::Lookup::unlookup(hunter.clone());
+        // reassignment settles the stream of the old hunter
+        Module::<T>::assign_bounty_impl(bounty_id, funder.clone(), hunters.remove(0))?;
+        advance_blocks::<T>(10u32.into());
+    }: _(RawOrigin::Signed(funder), bounty_id, hunter_lookup)
+    verify {
+        assert_eq!(Module::<T>::hunted_for_bounty(bounty_id), hunter);
+    }
+
+    unassign_bounty {
+        let funder = funded_account::<T>("funder", 0);
+        let (bounty_id, _) = assigned::<T>(&funder);
+    }: _(RawOrigin::Signed(funder), bounty_id)
+
+    approve_extension {
+        let funder = funded_account::<T>("funder", 0);
+        let (bounty_id, hunter) = assigned::<T>(&funder);
+        // worst case: the history is full and the extension is not free
+        for _ in 1..Module::<T>::max_extensions() {
+            Module::<T>::request_extension_impl(bounty_id, hunter.clone(), 100u32.into(), Default::default())?;
+            Module::<T>::approve_extension_impl(bounty_id, funder.clone(), true)?;
+        }
+        Module::<T>::request_extension_impl(bounty_id, hunter, 100u32.into(), Default::default())?;
+    }: _(RawOrigin::Signed(funder), bounty_id, true)
+
+    decline_hunter {
+        let funder = funded_account::<T>("funder", 0);
+        let bounty_id = accepted::<T>(&funder);
+        let hunter = hunt::<T>(bounty_id, "hunter", 1).remove(0);
+        let hunter_lookup = T::Lookup::unlookup(hunter);
+    }: _(RawOrigin::Signed(funder), bounty_id, hunter_lookup, DeclineReason::Unqualified)
+
+    shortlist_hunter {
+        let funder = funded_account::<T>("funder", 0);
+        let bounty_id = accepted::<T>(&funder);
+        let hunter = hunt::<T>(bounty_id, "hunter", 1).remove(0);
+        let hunter_lookup = T::Lookup::unlookup(hunter);
+    }: _(RawOrigin::Signed(funder), bounty_id, hunter_lookup, true)
+
+    set_max_applicants {
+        let funder = funded_account::<T>("funder", 0);
+        let bounty_id = accepted::<T>(&funder);
+    }: _(RawOrigin::Signed(funder), bounty_id, Some(10))
+
+    set_streaming {
+        let funder = funded_account::<T>("funder", 0);
+        let bounty_id = accepted::<T>(&funder);
+    }: _(RawOrigin::Signed(funder), bounty_id, true)
+
+    set_prerequisites {
+        let p in 0 .. MAX_PREREQUISITES;
+        let funder = funded_account::<T>("funder", 0);
+        let prerequisites = (0..p).map(|_| create::<T>(&funder)).collect::<Vec<_>>();
+        let bounty_id = create::<T>(&funder);
+    }: _(RawOrigin::Signed(funder), bounty_id, prerequisites)
+
+    create_template {
+        let owner = funded_account::<T>("owner", 0);
+    }: _(
+        RawOrigin::Signed(owner),
+        T::GetNativeCurrencyId::get(),
+        1_000_000_000_000u128.saturated_into(),
+        BountyCategory::Development,
+        vec![b'x'; 64],
+        Default::default()
+    )
+
+    remove_template {
+        let owner = funded_account::<T>("owner", 0);
+        new_template::<T>(&owner);
+        Module::<T>::schedule_template_impl(owner.clone(), 0, Some(10u32.into()))?;
+    }: _(RawOrigin::Signed(owner), 0)
+
+    instantiate_template {
+        let owner = funded_account::<T>("owner", 0);
+        new_template::<T>(&owner);
+    }: _(RawOrigin::Signed(owner), 0)
+
+    schedule_template {
+        let owner = funded_account::<T>("owner", 0);
+        new_template::<T>(&owner);
+    }: _(RawOrigin::Signed(owner), 0, Some(10u32.into()))
+
+    propose_bounty_owner {
+        let funder = funded_account::<T>("funder", 0);
+        let bounty_id = accepted::<T>(&funder);
+        let new_owner: T::AccountId = account("new_owner", 0, SEED);
+        let new_owner_lookup = T::Lookup::unlookup(new_owner);
+    }: _(RawOrigin::Signed(funder), bounty_id, new_owner_lookup)
+
+    accept_bounty_ownership {
+        let funder = funded_account::<T>("funder", 0);
+        let bounty_id = accepted::<T>(&funder);
+        let new_owner: T::AccountId = account("new_owner", 0, SEED);
+        Module::<T>::propose_bounty_owner_impl(bounty_id, funder, new_owner.clone())?;
+    }: _(RawOrigin::Signed(new_owner), bounty_id)
+
+    resolve_bounty_and_remark {
+        let funder = funded_account::<T>("funder", 0);
+        let (bounty_id, _) = submitted::<T>(&funder);
+        let tip = Some((T::GetNativeCurrencyId::get(), 1_000_000u128.saturated_into()));
+        let commitment = commitment::<T>(bounty_id, &funder);
+    }: _(RawOrigin::Signed(funder), bounty_id, commitment, tip)
+    verify {
+        assert!(Module::<T>::remark_rounds(bounty_id).is_some());
+    }
+
+    examine_bounty {
+        let d in 0 .. MAX_DEPENDENTS;
+        let funder = funded_account::<T>("funder", 0);
+        let bounty_id = create::<T>(&funder);
+        add_dependents::<T>(&funder, bounty_id, d);
+        let origin = T::CouncilOrigin::successful_origin();
+        let call = Call::<T>::examine_bounty(bounty_id, false);
+    }: { call.dispatch_bypass_filter(origin)? }
+
+    force_close_bounty {
+        let a in 0 .. MAX_APPLICANTS - 1;
+        let d in 0 .. MAX_DEPENDENTS;
+        let funder = funded_account::<T>("funder", 0);
+        // worst case: the assigned hunter is outdated and penalized
+        let (bounty_id, _) = assigned::<T>(&funder);
+        hunt::<T>(bounty_id, "applicant", a);
+        add_dependents::<T>(&funder, bounty_id, d);
+        advance_blocks::<T>(Module::<T>::outdated_height().saturating_add(1u32.into()));
+        let origin = T::CouncilOrigin::successful_origin();
+        let call = Call::<T>::force_close_bounty(bounty_id, CloseReason::Outdated);
+    }: { call.dispatch_bypass_filter(origin)? }
+
+    hunt_bounty {
+        let h in 0 .. MAX_HOLDING;
+        let a in 0 .. MAX_APPLICANTS - 1;
+        let funder = funded_account::<T>("funder", 0);
+        let hunter: T::AccountId = account("hunter", 0, SEED);
+        for _ in 0..h {
+            let held = accepted::<T>(&funder);
+            Module::<T>::hunt_bounty_impl(held, hunter.clone())?;
+        }
+        let bounty_id = accepted::<T>(&funder);
+        hunt::<T>(bounty_id, "applicant", a);
+    }: _(RawOrigin::Signed(hunter.clone()), bounty_id)
+    verify {
+        assert!(Module::<T>::hunting_for_bounty(bounty_id, &hunter));
+    }
+
+    submit_bounty {
+        let funder = funded_account::<T>("funder", 0);
+        let (bounty_id, hunter) = assigned::<T>(&funder);
+    }: _(RawOrigin::Signed(hunter), bounty_id)
+
+    cancel_hunt_bounty {
+        let funder = funded_account::<T>("funder", 0);
+        let bounty_id = accepted::<T>(&funder);
+        let hunter = hunt::<T>(bounty_id, "hunter", 1).remove(0);
+    }: _(RawOrigin::Signed(hunter), bounty_id)
+
+    resign_from_bounty {
+        let funder = funded_account::<T>("funder", 0);
+        let bounty_id = accepted::<T>(&funder);
+        Module::<T>::set_streaming_impl(bounty_id, funder.clone(), true)?;
+        let hunter = hunt::<T>(bounty_id, "hunter", 1).remove(0);
+        Module::<T>::assign_bounty_impl(bounty_id, funder, hunter.clone())?;
+        advance_blocks::<T>(10u32.into());
+    }: _(RawOrigin::Signed(hunter), bounty_id)
+
+    withdraw_vested {
+        let funder = funded_account::<T>("funder", 0);
+        let bounty_id = accepted::<T>(&funder);
+        Module::<T>::set_streaming_impl(bounty_id, funder.clone(), true)?;
+        let hunter = hunt::<T>(bounty_id, "hunter", 1).remove(0);
+        Module::<T>::assign_bounty_impl(bounty_id, funder, hunter.clone())?;
+        advance_blocks::<T>(10u32.into());
+    }: _(RawOrigin::Signed(hunter), bounty_id)
+
+    request_extension {
+        let funder = funded_account::<T>("funder", 0);
+        let (bounty_id, hunter) = assigned::<T>(&funder);
+    }: _(RawOrigin::Signed(hunter), bounty_id, 100u32.into(), Default::default())
+
+    remark_bounty_funder {
+        let a in 0 .. MAX_APPLICANTS - 1;
+        let funder = funded_account::<T>("funder", 0);
+        let bounty_id = accepted::<T>(&funder);
+        let hunter = hunt::<T>(bounty_id, "hunter", 1).remove(0);
+        hunt::<T>(bounty_id, "applicant", a);
+        Module::<T>::assign_bounty_impl(bounty_id, funder.clone(), hunter.clone())?;
+        Module::<T>::submit_bounty_impl(bounty_id, hunter.clone())?;
+        Module::<T>::resolve_bounty_and_remark_impl(
+            bounty_id,
+            funder.clone(),
+            commitment::<T>(bounty_id, &funder),
+            None,
+        )?;
+        let commitment = commitment::<T>(bounty_id, &hunter);
+    }: _(RawOrigin::Signed(hunter), bounty_id, commitment)
+
+    // the last reveal applies the remarks
+    reveal_remark {
+        let funder = funded_account::<T>("funder", 0);
+        let (bounty_id, hunter) = remarks_committed::<T>(&funder);
+        Module::<T>::reveal_remark_impl(
+            bounty_id,
+            funder,
+            BountyRemarkCollaborationResult::Good,
+            SALT,
+        )?;
+    }: _(RawOrigin::Signed(hunter), bounty_id, BountyRemarkCollaborationResult::Good, SALT)
+    verify {
+        assert!(Module::<T>::remark_rounds(bounty_id).is_none());
+    }
+
+    reap_bounty {
+        let p in 0 .. MAX_PREREQUISITES;
+        let a in 0 .. MAX_APPLICANTS;
+        let funder = funded_account::<T>("funder", 0);
+        let prerequisites = (0..p).map(|_| create::<T>(&funder)).collect::<Vec<_>>();
+        // worst case: the dependents of each prerequisite are full
+        for prerequisite in prerequisites.iter() {
+            add_dependents::<T>(&funder, *prerequisite, MAX_DEPENDENTS - 1);
+        }
+        let bounty_id = accepted::<T>(&funder);
+        Module::<T>::set_prerequisites_impl(bounty_id, funder.clone(), prerequisites)?;
+        for hunter in hunt::<T>(bounty_id, "declined", a) {
+            Module::<T>::decline_hunter_impl(
+                bounty_id,
+                funder.clone(),
+                hunter,
+                DeclineReason::Other,
+            )?;
+        }
+        Module::<T>::close_bounty_impl(funder.clone(), bounty_id)?;
+        advance_blocks::<T>(Module::<T>::retention_period());
+    }: _(RawOrigin::Signed(funder), bounty_id)
+    verify {
+        assert!(Module::<T>::archived_bounties(bounty_id).is_some());
+    }
+
+    spawn_template {
+        let owner = funded_account::<T>("owner", 0);
+        new_template::<T>(&owner);
+        Module::<T>::schedule_template_impl(owner, 0, Some(1u32.into()))?;
+        advance_blocks::<T>(1u32.into());
+        let now = frame_system::Module::<T>::block_number();
+    }: { Module::<T>::spawn_scheduled_templates(now) }
+    verify {
+        assert_eq!(Module::<T>::spawned_count(0), 1);
+    }
+
+    // the hunter missed the remark window
+    close_remark_window {
+        let a in 0 .. MAX_APPLICANTS - 1;
+        let funder = funded_account::<T>("funder", 0);
+        let bounty_id = accepted::<T>(&funder);
+        let hunter = hunt::<T>(bounty_id, "hunter", 1).remove(0);
+        let applicants = hunt::<T>(bounty_id, "applicant", a);
+        Module::<T>::assign_bounty_impl(bounty_id, funder.clone(), hunter.clone())?;
+        Module::<T>::submit_bounty_impl(bounty_id, hunter.clone())?;
+        Module::<T>::resolve_bounty_and_remark_impl(
+            bounty_id,
+            funder.clone(),
+            commitment::<T>(bounty_id, &funder),
+            None,
+        )?;
+        advance_blocks::<T>(Module::<T>::remark_commit_period());
+        let now = frame_system::Module::<T>::block_number();
+    }: { Module::<T>::process_remark_agenda(now) }
+    verify {
+        for applicant in applicants.iter() {
+            assert!(Module::<T>::hunter_bounties(applicant, bounty_id).is_none());
+        }
+    }
+
+    finalize_remark_round {
+        let funder = funded_account::<T>("funder", 0);
+        let (bounty_id, _) = remarks_committed::<T>(&funder);
+        advance_blocks::<T>(
+            Module::<T>::remark_commit_period().saturating_add(Module::<T>::remark_reveal_period()),
+        );
+        let now = frame_system::Module::<T>::block_number();
+    }: { Module::<T>::process_remark_agenda(now) }
+    verify {
+        assert!(Module::<T>::remark_rounds(bounty_id).is_none());
+    }
+}
@@ -0,0 +1,46 @@
+use frame_support::{dispatch::DispatchResult, ensure, storage::with_transaction};
+use sp_runtime::TransactionOutcome;
+use sp_std::prelude::*;
+
+use crate::{Error, Module, RawEvent, Trait};
+
+impl<T: Trait> Module<T> {
+    /// Run `f` for each item of a batch.
+    ///
+    /// An atomic batch reverts all items once one of them fails, while a best-effort batch only
+    /// reverts the failed item and goes on.
+    pub fn run_batch<I>(
+        items: Vec<I>,
+        atomic: bool,
+        f: impl Fn(I) -> DispatchResult,
+    ) -> DispatchResult {
+        ensure!(
+            items.len() as u32 <= Self::max_batch_size(),
+            Error::<T>::TooManyItems
+        );
+
+        if atomic {
+            return with_transaction(|| {
+                for (index, item) in items.into_iter().enumerate() {
+                    if let Err(e) = f(item) {
+                        return TransactionOutcome::Rollback(Err(e));
+                    }
+                    Self::deposit_event(RawEvent::BatchItemSucceeded(index as u32));
+                }
+                TransactionOutcome::Commit(Ok(()))
+            });
+        }
+
+        for (index, item) in items.into_iter().enumerate() {
+            let result = with_transaction(|| match f(item) {
+                Ok(()) => TransactionOutcome::Commit(Ok(())),
+                Err(e) => TransactionOutcome::Rollback(Err(e)),
+            });
+            match result {
+                Ok(()) => Self::deposit_event(RawEvent::BatchItemSucceeded(index as u32)),
+                Err(e) => Self::deposit_event(RawEvent::BatchItemFailed(index as u32, e)),
+            }
+        }
+        Ok(())
+    }
+}
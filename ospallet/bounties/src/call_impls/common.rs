@@ -2,16 +2,25 @@ use frame_support::{
     dispatch::DispatchError,
     dispatch::DispatchResult,
     ensure,
-    storage::{IterableStorageDoubleMap, StorageDoubleMap, StorageMap},
+    storage::{IterableStorageDoubleMap, IterableStorageMap, StorageDoubleMap, StorageMap},
+    traits::Get,
+    weights::Weight,
 };
+use sp_runtime::traits::{SaturatedConversion, Saturating};
 use sp_std::{prelude::*, result};
 
 use opensquare_primitives::BountyId;
+use orml_traits::MultiReservableCurrency;
+use ospallet_reputation::BountyContext;
 
-use crate::types::{Bounty, BountyOf, BountyState};
+use crate::types::{Bounty, BountyCategory, BountyOf, BountyState, BountySummary};
 use crate::{
-    ApprovedHeight, AssignedHeight, BalanceOf, BountyStateOf, CurrencyIdOf, Error, HuntedForBounty,
-    HunterBounties, HuntingForBounty, Module, Trait,
+    ApprovedHeight, ArchivedBounties, AssignedHeight, BalanceOf, Bounties, BountiesByCategory,
+    BountiesByState, BountiesOf, BountyStateOf, BrokenPrerequisites, CurrencyIdOf, Deadline,
+    DeclinedCount, DeclinedHunters, Dependents, Error, ExtensionHistory, FinishedHeight,
+    HuntedForBounty, HunterBounties, HuntingForBounty, LastActivityHeight, MaxApplicants, Module,
+    PendingExtension, PendingOwner, Prerequisites, RawEvent, ReapDepositOf, RemarkRounds,
+    Shortlisted, StreamOf, Streaming, Trait,
 };
 
 impl<T: Trait> Module<T> {
@@ -23,12 +32,45 @@ impl<T: Trait> Module<T> {
     pub fn get_funder(bounty: &BountyOf<T>) -> T::AccountId {
         match bounty {
             Bounty::V1(ref metadata) => metadata.owner.clone(),
+            Bounty::V2(ref metadata) => metadata.owner.clone(),
+        }
+    }
+
+    pub fn set_funder(bounty: &mut BountyOf<T>, funder: T::AccountId) {
+        match bounty {
+            Bounty::V1(ref mut metadata) => metadata.owner = funder,
+            Bounty::V2(ref mut metadata) => metadata.owner = funder,
+        }
+    }
+
+    pub fn get_category(bounty: &BountyOf<T>) -> BountyCategory {
+        match bounty {
+            Bounty::V1(_) => BountyCategory::Development,
+            Bounty::V2(ref metadata) => metadata.category,
+        }
+    }
+
+    /// Context of a reputation behavior in the bounty, the payment is normalized by `CurrencyRatios`.
+    pub fn bounty_context(
+        bounty_id: BountyId,
+        bounty: &BountyOf<T>,
+        counterparty: &T::AccountId,
+    ) -> BountyContext<T::AccountId> {
+        let (currency_id, payment) = Self::parse_payment(bounty);
+        BountyContext {
+            bounty_id,
+            category: Self::get_category(bounty),
+            counterparty: counterparty.clone(),
+            value: payment
+                .saturated_into::<u128>()
+                .saturating_mul(Self::currency_ratios(currency_id)),
         }
     }
 
     pub fn get_currency_id(bounty: &BountyOf<T>) -> CurrencyIdOf<T> {
         match bounty {
             Bounty::V1(ref metadata) => metadata.currency_id.clone(),
+            Bounty::V2(ref metadata) => metadata.currency_id.clone(),
         }
     }
 
@@ -41,17 +83,23 @@ impl<T: Trait> Module<T> {
     pub fn parse_payment(bounty: &BountyOf<T>) -> (CurrencyIdOf<T>, BalanceOf<T>) {
         match bounty {
             Bounty::V1(ref metadata) => (metadata.currency_id, metadata.payment),
+            Bounty::V2(ref metadata) => (metadata.currency_id, metadata.payment),
         }
     }
 
     pub fn remove_hunters_for_bounty(bounty_id: BountyId) {
-        // remove hunters for a bounty
-        let mut hunters = HuntingForBounty::<T>::drain_prefix(bounty_id)
+        Self::remove_applicants_for_bounty(bounty_id);
+        // remove the assigned hunter
+        let hunted_hunter = HuntedForBounty::<T>::take(&bounty_id);
+        HunterBounties::<T>::remove(hunted_hunter, bounty_id);
+    }
+
+    /// remove all applicants for a bounty, `HuntedForBounty` of the assigned hunter is kept
+    pub fn remove_applicants_for_bounty(bounty_id: BountyId) {
+        Shortlisted::<T>::remove_prefix(bounty_id);
+        let hunters = HuntingForBounty::<T>::drain_prefix(bounty_id)
             .map(|(a, _)| a)
             .collect::<Vec<_>>();
-        let hunted_hunter = HuntedForBounty::<T>::take(&bounty_id);
-        // hunters.extend(accounts);
-        hunters.push(hunted_hunter);
         // remove bounty for hunters
         for hunter in hunters {
             HunterBounties::<T>::remove(hunter, bounty_id)
@@ -65,32 +113,264 @@ impl<T: Trait> Module<T> {
         HunterBounties::<T>::remove(&hunter, bounty_id);
         // 3
         HuntingForBounty::<T>::remove(bounty_id, &hunter);
+        Shortlisted::<T>::remove(bounty_id, &hunter);
+        // deadline only works for the assigned hunter
+        LastActivityHeight::<T>::remove(bounty_id);
+        Deadline::<T>::remove(bounty_id);
+        PendingExtension::<T>::remove(bounty_id);
+    }
+
+    /// A bounty is resolved, even if it has been reaped.
+    pub fn is_resolved(bounty_id: BountyId) -> bool {
+        if BountyStateOf::contains_key(bounty_id) {
+            return Self::bounty_state_of(bounty_id) == BountyState::Resolved;
+        }
+        match Self::archived_bounties(bounty_id) {
+            Some(summary) => summary.outcome == BountyState::Resolved,
+            None => false,
+        }
+    }
+
+    /// Whether `who` is the hunter assigned to the bounty.
+    pub fn is_assigned_hunter(bounty_id: BountyId, who: &T::AccountId) -> bool {
+        HuntedForBounty::<T>::contains_key(bounty_id) && &Self::hunted_for_bounty(bounty_id) == who
+    }
+
+    pub fn check_prerequisites(bounty_id: BountyId) -> DispatchResult {
+        ensure!(
+            Self::prerequisites(bounty_id)
+                .into_iter()
+                .all(Self::is_resolved),
+            Error::<T>::PrerequisitesUnresolved
+        );
+        Ok(())
+    }
+
+    /// Flag dependents of a closed or rejected bounty and notify their funders, finished dependents
+    /// are skipped.
+    fn notify_dependents(bounty_id: BountyId) {
+        for dependent in Self::dependents(bounty_id) {
+            let bounty = match Self::bounties(dependent) {
+                Some(bounty) => bounty,
+                None => continue,
+            };
+            if Self::bounty_state_of(dependent).is_finished() {
+                continue;
+            }
+            BrokenPrerequisites::mutate(dependent, |list| {
+                if !list.contains(&bounty_id) {
+                    list.push(bounty_id);
+                }
+            });
+            Self::deposit_event(RawEvent::PrerequisiteFailed(
+                dependent,
+                bounty_id,
+                Self::get_funder(&bounty),
+            ));
+        }
+    }
+
+    /// Record an action of the assigned hunter, inactivity is measured from the last one.
+    pub fn note_hunter_activity(bounty_id: BountyId) {
+        LastActivityHeight::<T>::insert(bounty_id, frame_system::Module::<T>::block_number());
+    }
+
+    /// An assigned bounty is outdated when its deadline(including approved extensions) is passed.
+    pub fn is_outdated(bounty_id: BountyId) -> bool {
+        if Self::bounty_state_of(bounty_id) != BountyState::Assigned {
+            return false;
+        }
+        match Self::deadline(bounty_id) {
+            Some(deadline) => frame_system::Module::<T>::block_number() > deadline,
+            None => false,
+        }
     }
 
     pub fn change_state(bounty_id: BountyId, state: BountyState) {
         match state {
             BountyState::Assigned => {
                 AssignedHeight::<T>::insert(bounty_id, frame_system::Module::<T>::block_number());
+                Self::note_hunter_activity(bounty_id);
             }
             BountyState::Applying => {
                 ApprovedHeight::<T>::insert(bounty_id, frame_system::Module::<T>::block_number());
             }
+            _ if state.is_finished() => {
+                FinishedHeight::<T>::insert(bounty_id, frame_system::Module::<T>::block_number());
+                // a bounty once resolved never fails its dependents
+                if state != BountyState::Resolved && !Self::is_resolved(bounty_id) {
+                    Self::notify_dependents(bounty_id);
+                }
+            }
             _ => { /* do nothing*/ }
         }
+        // keep state index consistent
+        if BountyStateOf::contains_key(bounty_id) {
+            BountiesByState::remove(Self::bounty_state_of(bounty_id), bounty_id);
+        }
+        BountiesByState::insert(state, bounty_id, ());
         BountyStateOf::insert(bounty_id, state);
     }
 
+    /// Get bounty ids in a state from the state index, skip `offset` items and return at most `limit` items.
+    pub fn bounties_in_state(state: BountyState, offset: u32, limit: u32) -> Vec<BountyId> {
+        BountiesByState::iter_prefix(state)
+            .map(|(id, _)| id)
+            .skip(offset as usize)
+            .take(limit as usize)
+            .collect()
+    }
+
+    /// Get bounty ids in a category from the category index, skip `offset` items and return at most `limit` items.
+    pub fn bounties_in_category(
+        category: BountyCategory,
+        offset: u32,
+        limit: u32,
+    ) -> Vec<BountyId> {
+        BountiesByCategory::iter_prefix(category)
+            .map(|(id, _)| id)
+            .skip(offset as usize)
+            .take(limit as usize)
+            .collect()
+    }
+
+    /// Index existing bounties by their states, return the number of indexed bounties.
+    pub fn build_state_index() -> Weight {
+        let mut indexed: Weight = 0;
+        for (bounty_id, state) in BountyStateOf::iter() {
+            BountiesByState::insert(state, bounty_id, ());
+            indexed += 1;
+        }
+        indexed
+    }
+
+    /// Upgrade existing bounties to the latest layout and index them by their categories, return the
+    /// number of upgraded bounties.
+    pub fn upgrade_bounties() -> Weight {
+        let bounties = Bounties::<T>::iter().collect::<Vec<_>>();
+        let mut upgraded: Weight = 0;
+        for (bounty_id, bounty) in bounties {
+            let bounty = bounty.upgrade();
+            BountiesByCategory::insert(Self::get_category(&bounty), bounty_id, ());
+            Bounties::<T>::insert(bounty_id, bounty);
+            // bounties assigned before deadlines have the default one from their assignment
+            let state = Self::bounty_state_of(bounty_id);
+            if (state == BountyState::Assigned || state == BountyState::Submitted)
+                && !Deadline::<T>::contains_key(bounty_id)
+            {
+                let deadline =
+                    Self::assigned_height(bounty_id).saturating_add(Self::outdated_height());
+                Deadline::<T>::insert(bounty_id, deadline);
+            }
+            upgraded += 1;
+        }
+        upgraded
+    }
+
+    /// Check the state and category indices are consistent with `BountyStateOf` and `Bounties`.
+    pub fn check_indices() -> result::Result<(), &'static str> {
+        for (bounty_id, state) in BountyStateOf::iter() {
+            if !BountiesByState::contains_key(state, bounty_id) {
+                return Err("bounty is missing in the state index");
+            }
+        }
+        for (state, bounty_id, _) in BountiesByState::iter() {
+            if !BountyStateOf::contains_key(bounty_id) || Self::bounty_state_of(bounty_id) != state
+            {
+                return Err("state index contains a stale bounty");
+            }
+        }
+        for (bounty_id, bounty) in Bounties::<T>::iter() {
+            if !BountiesByCategory::contains_key(Self::get_category(&bounty), bounty_id) {
+                return Err("bounty is missing in the category index");
+            }
+        }
+        for (category, bounty_id, _) in BountiesByCategory::iter() {
+            match Self::bounties(bounty_id) {
+                Some(bounty) if Self::get_category(&bounty) == category => {}
+                _ => return Err("category index contains a stale bounty"),
+            }
+        }
+        Ok(())
+    }
+
     pub fn check_bounty_can_be_closed(bounty_id: BountyId) -> DispatchResult {
-        let state = Self::bounty_state_of(bounty_id);
+        // No meaning to close a finished bounty, terminal states never change
         ensure!(
-            // No meaning to close a rejected bounty
-            (state != BountyState::Rejected)
-                || (state != BountyState::Closed)
-                || (state != BountyState::Outdated)
-                || (state != BountyState::Resolved),
+            !Self::bounty_state_of(bounty_id).is_finished(),
             Error::<T>::InvalidState
         );
 
         Ok(())
     }
+
+    pub fn reap_bounty_impl(bounty_id: BountyId, reaper: T::AccountId) -> DispatchResult {
+        let bounty = Self::get_bounty(&bounty_id)?;
+        let state = Self::bounty_state_of(bounty_id);
+        ensure!(state.is_finished(), Error::<T>::NotReapable);
+        // remarks are applied with the bounty
+        ensure!(
+            !RemarkRounds::<T>::contains_key(bounty_id),
+            Error::<T>::NotReapable
+        );
+
+        let finished_at = Self::finished_height(bounty_id);
+        let now = frame_system::Module::<T>::block_number();
+        ensure!(
+            now >= finished_at.saturating_add(Self::retention_period()),
+            Error::<T>::NotReapable
+        );
+
+        let funder = Self::get_funder(&bounty);
+        let (currency_id, payment) = Self::parse_payment(&bounty);
+        // only a resolved bounty still keeps the assigned hunter
+        let hunter = if HuntedForBounty::<T>::contains_key(bounty_id) {
+            Some(Self::hunted_for_bounty(bounty_id))
+        } else {
+            None
+        };
+        Self::remove_hunters_for_bounty(bounty_id);
+
+        // refund reap deposit
+        let deposit = ReapDepositOf::<T>::take(bounty_id);
+        T::Currency::unreserve(T::GetNativeCurrencyId::get(), &funder, deposit);
+
+        Bounties::<T>::remove(bounty_id);
+        BountiesByState::remove(state, bounty_id);
+        BountiesByCategory::remove(Self::get_category(&bounty), bounty_id);
+        BountyStateOf::remove(bounty_id);
+        ApprovedHeight::<T>::remove(bounty_id);
+        AssignedHeight::<T>::remove(bounty_id);
+        LastActivityHeight::<T>::remove(bounty_id);
+        FinishedHeight::<T>::remove(bounty_id);
+        PendingOwner::<T>::remove(bounty_id);
+        Deadline::<T>::remove(bounty_id);
+        PendingExtension::<T>::remove(bounty_id);
+        ExtensionHistory::<T>::remove(bounty_id);
+        for prerequisite in Prerequisites::take(bounty_id) {
+            Dependents::mutate(prerequisite, |list| list.retain(|id| id != &bounty_id));
+        }
+        Dependents::remove(bounty_id);
+        BrokenPrerequisites::remove(bounty_id);
+        Streaming::remove(bounty_id);
+        StreamOf::<T>::remove(bounty_id);
+        DeclinedHunters::<T>::remove_prefix(bounty_id);
+        DeclinedCount::remove(bounty_id);
+        MaxApplicants::remove(bounty_id);
+        BountiesOf::<T>::mutate(&funder, |list| list.retain(|id| id != &bounty_id));
+
+        ArchivedBounties::<T>::insert(
+            bounty_id,
+            BountySummary {
+                funder,
+                hunter,
+                currency_id,
+                payment,
+                outcome: state,
+                finished_at,
+            },
+        );
+        Self::deposit_event(RawEvent::Reaped(bounty_id, reaper));
+        Ok(())
+    }
 }
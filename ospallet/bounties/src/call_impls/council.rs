@@ -2,6 +2,8 @@ use frame_support::{dispatch::DispatchResult, ensure};
 
 use opensquare_primitives::BountyId;
 use orml_traits::MultiReservableCurrency;
+use ospallet_reputation::{Behavior, BountyResolveCollaborationResult, ReputationBuilder, Role};
+use sp_runtime::traits::Saturating;
 
 use crate::types::BountyState;
 use crate::types::CloseReason;
@@ -33,13 +35,39 @@ impl<T: Trait> Module<T> {
         let bounty = Self::get_bounty(&bounty_id)?;
         let funder = Self::get_funder(&bounty);
         let (id, locked) = Self::parse_payment(&bounty);
+        Self::check_bounty_can_be_closed(bounty_id)?;
+        // the assigned hunter could only be outdated after the deadline(including approved extensions)
+        let outdated = Self::is_outdated(bounty_id);
+        if let CloseReason::Outdated = reason {
+            ensure!(
+                outdated || Self::bounty_state_of(bounty_id) != BountyState::Assigned,
+                Error::<T>::NotOutdated
+            );
+        }
+        // pay the vested part to the hunter, only the unvested remainder is refunded
+        Self::settle_stream(bounty_id, &bounty)?;
+        if outdated {
+            T::ReputationBuilder::add_behavior_score_by_behavior(
+                &Self::hunted_for_bounty(bounty_id),
+                Role::Hunter,
+                &Behavior::BountyResolve(BountyResolveCollaborationResult::Fail),
+                &Self::bounty_context(bounty_id, &bounty, &funder),
+            );
+        }
         // remove hunter for a bounty
         Self::remove_hunters_for_bounty(bounty_id);
-        Self::check_bounty_can_be_closed(bounty_id)?;
         // release reserved balance, todo maybe use log to print it
-        let remaining = T::Currency::unreserve(id, &funder, locked);
+        let remaining = T::Currency::unreserve(
+            id,
+            &funder,
+            locked.saturating_sub(Self::streamed(bounty_id)),
+        );
 
-        Self::change_state(bounty_id, BountyState::CouncilClosed);
+        if outdated {
+            Self::change_state(bounty_id, BountyState::Outdated);
+        } else {
+            Self::change_state(bounty_id, BountyState::CouncilClosed);
+        }
         Self::deposit_event(RawEvent::ForceClosed(bounty_id, reason, remaining));
 
         Ok(())
@@ -1,37 +1,52 @@
 use frame_support::{
-    dispatch::DispatchResult,
+    dispatch::{DispatchError, DispatchResult},
     ensure,
-    storage::{StorageDoubleMap, StorageMap},
+    storage::{StorageDoubleMap, StorageMap, StorageValue},
     traits::{BalanceStatus, Get},
 };
 
 use opensquare_primitives::BountyId;
-use orml_traits::MultiReservableCurrency;
+use orml_traits::{MultiCurrency, MultiReservableCurrency};
 use ospallet_mining::MiningPowerBuilder;
-use ospallet_reputation::{
-    Behavior, BountyRemarkCollaborationResult, BountyResolveCollaborationResult, ReputationBuilder,
-};
-use sp_runtime::traits::SaturatedConversion;
+use ospallet_reputation::{Behavior, BountyResolveCollaborationResult, ReputationBuilder, Role};
+use sp_runtime::traits::{SaturatedConversion, Saturating, Zero};
+use sp_std::{prelude::*, result};
 
-use crate::types::{BountyOf, BountyState, HunterBountyState};
+use crate::types::{BountyOf, BountyState, DeclineReason, HunterBountyState};
 use crate::{
-    BalanceOf, Bounties, BountiesOf, BountyIdFor, BountyResolved, BountyStateOf, Error,
-    HuntedForBounty, HunterBounties, Module, RawEvent, Trait,
+    ArchivedBounties, AssignedHeight, BalanceOf, Bounties, BountiesByCategory, BountiesOf,
+    BountyCount, BountyIdByIndex, BountyIdFor, BountyIndexOf, BountyResolved, BountyStateOf,
+    BrokenPrerequisites, CurrencyIdOf, Deadline, DeclinedCount, DeclinedHunters, Dependents, Error,
+    ExtensionHistory, HuntedForBounty, HunterBounties, HuntingForBounty, LastActivityHeight,
+    MaxApplicants, Module, PendingExtension, PendingOwner, Prerequisites, RawEvent, ReapDepositOf,
+    ResolvedCount, Shortlisted, Trait,
 };
 
 impl<T: Trait> Module<T> {
-    pub fn create_bounty_impl(creator: T::AccountId, bounty: BountyOf<T>) -> DispatchResult {
+    pub fn create_bounty_impl(
+        creator: T::AccountId,
+        bounty: BountyOf<T>,
+    ) -> result::Result<BountyId, DispatchError> {
+        // bounties are always kept in the latest layout
+        let bounty = bounty.upgrade();
         let bounty_id = T::DetermineBountyId::bounty_id_for(&creator);
 
         ensure!(!BountyStateOf::contains_key(bounty_id), Error::<T>::Existed);
         ensure!(Self::bounties(bounty_id).is_none(), Error::<T>::Existed);
+        ensure!(
+            !ArchivedBounties::<T>::contains_key(bounty_id),
+            Error::<T>::Existed
+        );
 
         Self::check_funder(&creator, &bounty)?;
 
         // reserve balance and other init
-        Self::handle_init_bounty(&creator, &bounty)?;
+        let deposit = Self::handle_init_bounty(&creator, &bounty)?;
 
+        ReapDepositOf::<T>::insert(bounty_id, deposit);
+        BountiesByCategory::insert(Self::get_category(&bounty), bounty_id, ());
         Bounties::<T>::insert(bounty_id, bounty);
+        Self::note_bounty_index(bounty_id);
         BountiesOf::<T>::mutate(&creator, |list| {
             if !list.contains(&bounty_id) {
                 list.push(bounty_id);
@@ -39,19 +54,40 @@ impl<T: Trait> Module<T> {
         });
         Self::change_state(bounty_id, BountyState::Applying);
         Self::deposit_event(RawEvent::ApplyBounty(creator, bounty_id));
-        Ok(())
+        Ok(bounty_id)
+    }
+
+    /// assign next sequential index to a new bounty
+    fn note_bounty_index(bounty_id: BountyId) {
+        let index = Self::bounty_count();
+        BountyIdByIndex::insert(index, bounty_id);
+        BountyIndexOf::insert(bounty_id, index);
+        BountyCount::put(index.saturating_add(1));
     }
 
-    fn handle_init_bounty(funder: &T::AccountId, bounty: &BountyOf<T>) -> DispatchResult {
+    /// reserve the payment and the reap deposit, return the reserved deposit
+    fn handle_init_bounty(
+        funder: &T::AccountId,
+        bounty: &BountyOf<T>,
+    ) -> result::Result<BalanceOf<T>, DispatchError> {
         let (id, locked) = Self::parse_payment(&bounty);
+        let native_id = T::GetNativeCurrencyId::get();
+        let deposit = T::ReapDeposit::get();
 
-        if !T::Currency::can_reserve(id, funder, locked) {
+        let can_pay = if id == native_id {
+            T::Currency::can_reserve(id, funder, locked.saturating_add(deposit))
+        } else {
+            T::Currency::can_reserve(id, funder, locked)
+                && T::Currency::can_reserve(native_id, funder, deposit)
+        };
+        if !can_pay {
             Err(Error::<T>::CantPay)?
         }
 
         T::Currency::reserve(id, funder, locked)?;
+        T::Currency::reserve(native_id, funder, deposit)?;
 
-        Ok(())
+        Ok(deposit)
     }
 
     pub fn close_bounty_impl(funder: T::AccountId, bounty_id: BountyId) -> DispatchResult {
@@ -59,9 +95,15 @@ impl<T: Trait> Module<T> {
         Self::check_funder(&funder, &bounty)?;
         Self::check_bounty_can_be_closed(bounty_id)?;
 
+        // pay the vested part to the hunter, only the unvested remainder is refunded
+        Self::settle_stream(bounty_id, &bounty)?;
         let (id, locked) = Self::parse_payment(&bounty);
         // release reserved balance
-        let remaining = T::Currency::unreserve(id, &funder, locked);
+        let remaining = T::Currency::unreserve(
+            id,
+            &funder,
+            locked.saturating_sub(Self::streamed(bounty_id)),
+        );
         // remove hunter for a bounty
         Self::remove_hunters_for_bounty(bounty_id);
 
@@ -83,11 +125,21 @@ impl<T: Trait> Module<T> {
             Error::<T>::InvalidState
         );
 
+        Self::check_prerequisites(bounty_id)?;
+
         // judge new hunter is in hunting list
         ensure!(
             Self::hunting_for_bounty(bounty_id, &hunter),
             Error::<T>::NotHunter
         );
+        if HuntedForBounty::<T>::contains_key(bounty_id) {
+            ensure!(
+                Self::hunted_for_bounty(bounty_id) != hunter,
+                Error::<T>::AlreadyAssigned
+            );
+            // old hunter takes the vested part
+            Self::settle_stream(bounty_id, &bounty)?;
+        }
         HuntedForBounty::<T>::try_mutate_exists(bounty_id, |option| -> DispatchResult {
             if let Some(old_hunter) = option {
                 if old_hunter == &hunter {
@@ -104,16 +156,322 @@ impl<T: Trait> Module<T> {
             Ok(())
         })?;
 
+        // a new deadline for the new hunter
+        let now = frame_system::Module::<T>::block_number();
+        Deadline::<T>::insert(bounty_id, now.saturating_add(Self::outdated_height()));
+        PendingExtension::<T>::remove(bounty_id);
+
         Self::change_state(bounty_id, BountyState::Assigned);
         Self::deposit_event(RawEvent::AssignBounty(bounty_id, hunter));
         Ok(())
     }
 
+    pub fn set_prerequisites_impl(
+        bounty_id: BountyId,
+        funder: T::AccountId,
+        prerequisites: Vec<BountyId>,
+    ) -> DispatchResult {
+        let bounty = Self::get_bounty(&bounty_id)?;
+        Self::check_funder(&funder, &bounty)?;
+        let state = Self::bounty_state_of(bounty_id);
+        ensure!(
+            (state == BountyState::Applying) || (state == BountyState::Accepted),
+            Error::<T>::InvalidState
+        );
+        ensure!(
+            prerequisites.len() as u32 <= Self::max_prerequisites(),
+            Error::<T>::InvalidPrerequisites
+        );
+        let old_prerequisites = Self::prerequisites(bounty_id);
+        // prerequisites are created before the bounty, so the dependencies never make a cycle
+        let index = Self::bounty_index_of(bounty_id);
+        for (i, prerequisite) in prerequisites.iter().enumerate() {
+            ensure!(
+                Self::bounties(prerequisite).is_some() || Self::is_resolved(*prerequisite),
+                Error::<T>::InvalidPrerequisites
+            );
+            // bound the dependents to walk when the prerequisite fails
+            ensure!(
+                old_prerequisites.contains(prerequisite)
+                    || (Dependents::decode_len(prerequisite).unwrap_or(0) as u32)
+                        < Self::max_dependents(),
+                Error::<T>::TooManyDependents
+            );
+            ensure!(
+                !prerequisites[..i].contains(prerequisite),
+                Error::<T>::InvalidPrerequisites
+            );
+            ensure!(
+                Self::bounty_index_of(prerequisite) < index,
+                Error::<T>::InvalidPrerequisites
+            );
+        }
+
+        for old in old_prerequisites {
+            Dependents::mutate(old, |list| list.retain(|id| id != &bounty_id));
+        }
+        for new in prerequisites.iter() {
+            Dependents::mutate(new, |list| list.push(bounty_id));
+        }
+        if prerequisites.is_empty() {
+            Prerequisites::remove(bounty_id);
+        } else {
+            Prerequisites::insert(bounty_id, &prerequisites);
+        }
+        BrokenPrerequisites::remove(bounty_id);
+
+        Self::deposit_event(RawEvent::PrerequisitesSet(bounty_id, prerequisites));
+        Ok(())
+    }
+
+    pub fn unassign_bounty_impl(bounty_id: BountyId, funder: T::AccountId) -> DispatchResult {
+        let bounty = Self::get_bounty(&bounty_id)?;
+        Self::check_funder(&funder, &bounty)?;
+        ensure!(
+            Self::bounty_state_of(bounty_id) == BountyState::Assigned,
+            Error::<T>::InvalidState
+        );
+
+        // penalize hunter if inactive too long or outdated, otherwise penalize funder for unassigning early
+        let now = frame_system::Module::<T>::block_number();
+        let hunter_failed = Self::is_outdated(bounty_id)
+            || now
+                >= Self::last_activity_height(bounty_id)
+                    .saturating_add(Self::inactivity_threshold());
+
+        Self::settle_stream(bounty_id, &bounty)?;
+        let hunter = HuntedForBounty::<T>::take(bounty_id);
+        // hunter is back to hunting list
+        HunterBounties::<T>::insert(&hunter, bounty_id, HunterBountyState::Hunting);
+        AssignedHeight::<T>::remove(bounty_id);
+        LastActivityHeight::<T>::remove(bounty_id);
+        Deadline::<T>::remove(bounty_id);
+        PendingExtension::<T>::remove(bounty_id);
+
+        let (penalized, role, counterparty) = if hunter_failed {
+            (&hunter, Role::Hunter, &funder)
+        } else {
+            (&funder, Role::Funder, &hunter)
+        };
+        T::ReputationBuilder::add_behavior_score_by_behavior(
+            penalized,
+            role,
+            &Behavior::BountyResolve(BountyResolveCollaborationResult::Fail),
+            &Self::bounty_context(bounty_id, &bounty, counterparty),
+        );
+
+        Self::deposit_event(RawEvent::UnassignBounty(bounty_id, hunter));
+
+        Self::change_state(bounty_id, BountyState::Accepted);
+        Self::deposit_event(RawEvent::Accept(bounty_id));
+        Ok(())
+    }
+
+    pub fn approve_extension_impl(
+        bounty_id: BountyId,
+        funder: T::AccountId,
+        approved: bool,
+    ) -> DispatchResult {
+        let bounty = Self::get_bounty(&bounty_id)?;
+        Self::check_funder(&funder, &bounty)?;
+        ensure!(
+            Self::bounty_state_of(bounty_id) == BountyState::Assigned,
+            Error::<T>::InvalidState
+        );
+        let request =
+            PendingExtension::<T>::take(bounty_id).ok_or(Error::<T>::NoExtensionRequest)?;
+
+        let mut deadline = Self::deadline(bounty_id).unwrap_or_else(|| {
+            Self::assigned_height(bounty_id).saturating_add(Self::outdated_height())
+        });
+        if approved {
+            deadline = deadline.saturating_add(request.extra_blocks);
+            Deadline::<T>::insert(bounty_id, deadline);
+
+            // repeated extensions bring a mild reputation loss
+            let approved_count = Self::extension_history(bounty_id)
+                .iter()
+                .filter(|(r, approved)| *approved && r.hunter == request.hunter)
+                .count() as u32;
+            if approved_count >= Self::max_free_extensions() {
+                T::ReputationBuilder::add_behavior_score_by_behavior(
+                    &request.hunter,
+                    Role::Hunter,
+                    &Behavior::ExtensionOverused {
+                        extensions: approved_count.saturating_add(1),
+                    },
+                    &Self::bounty_context(bounty_id, &bounty, &funder),
+                );
+            }
+        }
+        ExtensionHistory::<T>::append(bounty_id, (request, approved));
+
+        Self::deposit_event(RawEvent::ExtensionExamined(bounty_id, approved, deadline));
+        Ok(())
+    }
+
+    pub fn decline_hunter_impl(
+        bounty_id: BountyId,
+        funder: T::AccountId,
+        hunter: T::AccountId,
+        reason: DeclineReason,
+    ) -> DispatchResult {
+        let bounty = Self::get_bounty(&bounty_id)?;
+        Self::check_funder(&funder, &bounty)?;
+        ensure!(
+            Self::hunting_for_bounty(bounty_id, &hunter),
+            Error::<T>::NotHunter
+        );
+        // the assigned hunter should be unassigned first
+        ensure!(
+            !(HuntedForBounty::<T>::contains_key(bounty_id)
+                && Self::hunted_for_bounty(bounty_id) == hunter),
+            Error::<T>::AlreadyAssigned
+        );
+        // bound the declined hunters to remove when the bounty is reaped
+        let declined = Self::declined_count(bounty_id);
+        ensure!(
+            declined < Self::applicants_limit(),
+            Error::<T>::TooManyDeclined
+        );
+
+        HuntingForBounty::<T>::remove(bounty_id, &hunter);
+        HunterBounties::<T>::remove(&hunter, bounty_id);
+        Shortlisted::<T>::remove(bounty_id, &hunter);
+        DeclinedHunters::<T>::insert(bounty_id, &hunter, reason);
+        DeclinedCount::insert(bounty_id, declined.saturating_add(1));
+
+        Self::deposit_event(RawEvent::DeclineHunter(bounty_id, hunter, reason));
+        Ok(())
+    }
+
+    pub fn shortlist_hunter_impl(
+        bounty_id: BountyId,
+        funder: T::AccountId,
+        hunter: T::AccountId,
+        shortlisted: bool,
+    ) -> DispatchResult {
+        let bounty = Self::get_bounty(&bounty_id)?;
+        Self::check_funder(&funder, &bounty)?;
+        ensure!(
+            Self::hunting_for_bounty(bounty_id, &hunter),
+            Error::<T>::NotHunter
+        );
+
+        if shortlisted {
+            Shortlisted::<T>::insert(bounty_id, &hunter, ());
+        } else {
+            Shortlisted::<T>::remove(bounty_id, &hunter);
+        }
+
+        Self::deposit_event(RawEvent::ShortlistHunter(bounty_id, hunter, shortlisted));
+        Ok(())
+    }
+
+    pub fn set_max_applicants_impl(
+        bounty_id: BountyId,
+        funder: T::AccountId,
+        max: Option<u32>,
+    ) -> DispatchResult {
+        let bounty = Self::get_bounty(&bounty_id)?;
+        Self::check_funder(&funder, &bounty)?;
+        ensure!(
+            !Self::bounty_state_of(bounty_id).is_finished(),
+            Error::<T>::InvalidState
+        );
+
+        // hunters already applied are kept, the limit only affects new applicants
+        match max {
+            Some(m) => MaxApplicants::insert(bounty_id, m),
+            None => MaxApplicants::remove(bounty_id),
+        }
+
+        Self::deposit_event(RawEvent::MaxApplicantsSet(bounty_id, max));
+        Ok(())
+    }
+
+    pub fn propose_bounty_owner_impl(
+        bounty_id: BountyId,
+        funder: T::AccountId,
+        new_owner: T::AccountId,
+    ) -> DispatchResult {
+        let bounty = Self::get_bounty(&bounty_id)?;
+        Self::check_funder(&funder, &bounty)?;
+        ensure!(
+            !Self::bounty_state_of(bounty_id).is_finished(),
+            Error::<T>::InvalidState
+        );
+        ensure!(new_owner != funder, Error::<T>::AlreadyOwner);
+        ensure!(
+            !Self::is_assigned_hunter(bounty_id, &new_owner),
+            Error::<T>::OwnerIsHunter
+        );
+
+        PendingOwner::<T>::insert(bounty_id, &new_owner);
+        Self::deposit_event(RawEvent::OwnershipProposed(bounty_id, new_owner));
+        Ok(())
+    }
+
+    pub fn accept_bounty_ownership_impl(
+        bounty_id: BountyId,
+        new_owner: T::AccountId,
+    ) -> DispatchResult {
+        let mut bounty = Self::get_bounty(&bounty_id)?;
+        ensure!(
+            Self::pending_owner(bounty_id).as_ref() == Some(&new_owner),
+            Error::<T>::NotPendingOwner
+        );
+        ensure!(
+            !Self::bounty_state_of(bounty_id).is_finished(),
+            Error::<T>::InvalidState
+        );
+        // the pending owner may be assigned after the proposal
+        ensure!(
+            !Self::is_assigned_hunter(bounty_id, &new_owner),
+            Error::<T>::OwnerIsHunter
+        );
+
+        let old_owner = Self::get_funder(&bounty);
+        let (id, locked) = Self::parse_payment(&bounty);
+        // move the escrow to new owner, it keeps reserved
+        let _ = T::Currency::repatriate_reserved(
+            id,
+            &old_owner,
+            &new_owner,
+            locked.saturating_sub(Self::streamed(bounty_id)),
+            BalanceStatus::Reserved,
+        )?;
+        let _ = T::Currency::repatriate_reserved(
+            T::GetNativeCurrencyId::get(),
+            &old_owner,
+            &new_owner,
+            Self::reap_deposit_of(bounty_id),
+            BalanceStatus::Reserved,
+        )?;
+
+        Self::set_funder(&mut bounty, new_owner.clone());
+        Bounties::<T>::insert(bounty_id, bounty);
+        PendingOwner::<T>::remove(bounty_id);
+
+        BountiesOf::<T>::mutate(&old_owner, |list| list.retain(|id| id != &bounty_id));
+        BountiesOf::<T>::mutate(&new_owner, |list| {
+            if !list.contains(&bounty_id) {
+                list.push(bounty_id);
+            }
+        });
+
+        Self::deposit_event(RawEvent::OwnershipTransferred(
+            bounty_id, old_owner, new_owner,
+        ));
+        Ok(())
+    }
+
     // todo, need remark score
     pub fn resolve_bounty_and_remark_impl(
         bounty_id: BountyId,
         funder: T::AccountId,
-        remark: BountyRemarkCollaborationResult,
+        remark_commitment: T::Hash,
+        tip: Option<(CurrencyIdOf<T>, BalanceOf<T>)>,
     ) -> DispatchResult {
         let bounty = Self::get_bounty(&bounty_id)?;
         Self::check_funder(&funder, &bounty)?;
@@ -124,18 +482,33 @@ impl<T: Trait> Module<T> {
 
         // TODO maybe other check
 
-        // release currency
         let hunter = Self::hunted_for_bounty(&bounty_id);
+        let council_account = T::CouncilAccount::get();
+        // pay tip from free balance, the call is transactional so a failure reverts it.
+        // when tips bring mining power, the council fee is charged on the tip like on payment
+        let tip = tip.filter(|(_, amount)| !amount.is_zero());
+        if let Some((tip_currency_id, amount)) = tip {
+            let tip_fee = if T::TipMining::get() {
+                T::CouncilFee::get() * amount
+            } else {
+                Zero::zero()
+            };
+            T::Currency::transfer(tip_currency_id, &funder, &hunter, amount - tip_fee)?;
+            if !tip_fee.is_zero() {
+                T::Currency::transfer(tip_currency_id, &funder, &council_account, tip_fee)?;
+            }
+        }
+
+        // release currency
         let (id, locked) = Self::parse_payment(&bounty);
 
         let fee = T::CouncilFee::get() * locked;
-        let council_account = T::CouncilAccount::get();
         // todo may be use log to print remaining
         let _ = T::Currency::repatriate_reserved(
             id,
             &funder,
             &hunter,
-            locked - fee,
+            (locked - fee).saturating_sub(Self::streamed(bounty_id)),
             BalanceStatus::Free,
         )?;
         let _ = T::Currency::repatriate_reserved(
@@ -146,38 +519,55 @@ impl<T: Trait> Module<T> {
             BalanceStatus::Free,
         )?;
 
+        // counted before the trigger, so the handlers see this bounty
+        ResolvedCount::<T>::mutate(&hunter, Self::get_category(&bounty), |count| {
+            *count = count.saturating_add(1)
+        });
         // trigger
-        T::BountyResolved::after_bounty_resolved(&bounty);
+        T::BountyResolved::after_bounty_resolved(&bounty, &hunter);
+
+        Self::_add_mining_power(id, fee, &funder, &hunter);
 
-        Self::_add_reputation(&hunter, remark);
-        Self::_add_mining_power(&bounty, fee, &funder, &hunter);
+        if let Some((tip_currency_id, amount)) = tip {
+            if T::TipMining::get() {
+                // tip is counted like payment by the fee charged on it
+                let tip_fee = T::CouncilFee::get() * amount;
+                Self::_add_mining_power(tip_currency_id, tip_fee, &funder, &hunter);
+            }
+            if T::TipReputation::get() {
+                T::ReputationBuilder::add_behavior_score_by_behavior(
+                    &hunter,
+                    Role::Hunter,
+                    &Behavior::BountyTipped,
+                    &Self::bounty_context(bounty_id, &bounty, &funder),
+                );
+            }
+        }
 
+        // the resolved bounty no longer holds a slot of the hunter, `HuntedForBounty` is kept for
+        // the remark round and the archive
+        HunterBounties::<T>::remove(&hunter, bounty_id);
         Self::change_state(bounty_id, BountyState::Resolved);
         Self::deposit_event(RawEvent::Resolve(bounty_id));
-        Self::deposit_event(RawEvent::FunderRemark(bounty_id, hunter, remark));
-        // TODO maybe delete storage to save disk space
+        if let Some((tip_currency_id, amount)) = tip {
+            Self::deposit_event(RawEvent::Tip(
+                bounty_id,
+                hunter.clone(),
+                tip_currency_id,
+                amount,
+            ));
+        }
+        Self::open_remark_round(bounty_id, funder, hunter, remark_commitment);
 
         Ok(())
     }
 
-    fn _add_reputation(hunter: &T::AccountId, remark: BountyRemarkCollaborationResult) {
-        T::ReputationBuilder::add_behavior_score_by_behavior(
-            hunter,
-            &Behavior::BountyResolve(BountyResolveCollaborationResult::Success),
-        );
-        T::ReputationBuilder::add_behavior_score_by_behavior(
-            hunter,
-            &Behavior::BountyRemark(remark),
-        );
-    }
-
     fn _add_mining_power(
-        bounty: &BountyOf<T>,
+        currency_id: CurrencyIdOf<T>,
         fee: BalanceOf<T>,
         funder: &T::AccountId,
         hunter: &T::AccountId,
     ) {
-        let currency_id = Self::get_currency_id(bounty);
         let ratio = Self::currency_ratios(currency_id);
 
         let total_power = fee.saturated_into::<u128>() * ratio;
@@ -2,13 +2,14 @@ use frame_support::{
     dispatch::DispatchResult, ensure, storage::StorageDoubleMap, IterableStorageDoubleMap,
 };
 
-use opensquare_primitives::BountyId;
-use ospallet_reputation::{
-    Behavior, BountyRemarkCollaborationResult, BountyResolveCollaborationResult, ReputationBuilder,
-};
+use opensquare_primitives::{BountyId, SdDigest};
+use ospallet_reputation::{Behavior, BountyResolveCollaborationResult, ReputationBuilder, Role};
 
-use crate::types::{BountyState, HunterBountyState};
-use crate::{Error, HunterBounties, HuntingForBounty, Module, RawEvent, Trait};
+use crate::types::{BountyState, ExtensionRequest, HunterBountyState};
+use crate::{
+    DeclinedHunters, Error, ExtensionHistory, HunterBounties, HuntingForBounty, Module,
+    PendingExtension, RawEvent, Shortlisted, Trait,
+};
 
 impl<T: Trait> Module<T> {
     pub fn hunt_bounty_impl(bounty_id: BountyId, hunter: T::AccountId) -> DispatchResult {
@@ -19,17 +20,31 @@ impl<T: Trait> Module<T> {
                 || (state == BountyState::Submitted),
             Error::<T>::InvalidState
         );
+        Self::check_prerequisites(bounty_id)?;
 
         // this count include hunting and processing, if not need processing, should filter this
-        let count = HunterBounties::<T>::iter_prefix(&hunter).count();
+        let max_holding = Self::max_holding_bounties();
+        let count = HunterBounties::<T>::iter_prefix(&hunter)
+            .take(max_holding as usize + 1)
+            .count();
         ensure!(
-            count as u32 <= Self::max_holding_bounties(),
+            count as u32 <= max_holding,
             Error::<T>::TooManyHuntedBounties
         );
         ensure!(
             !HunterBounties::<T>::contains_key(&hunter, &bounty_id),
             Error::<T>::AlreadyHunted
         );
+        ensure!(
+            !DeclinedHunters::<T>::contains_key(&bounty_id, &hunter),
+            Error::<T>::Declined
+        );
+        let limit = Self::applicants_limit();
+        let max = Self::max_applicants(bounty_id).map_or(limit, |max| max.min(limit));
+        let applicants = HuntingForBounty::<T>::iter_prefix(&bounty_id)
+            .take(max as usize)
+            .count();
+        ensure!((applicants as u32) < max, Error::<T>::TooManyApplicants);
 
         HunterBounties::<T>::insert(&hunter, bounty_id, HunterBountyState::Hunting);
         HuntingForBounty::<T>::insert(bounty_id, &hunter, true);
@@ -62,6 +77,7 @@ impl<T: Trait> Module<T> {
 
         HuntingForBounty::<T>::remove(&bounty_id, &hunter);
         HunterBounties::<T>::remove(&hunter, &bounty_id);
+        Shortlisted::<T>::remove(&bounty_id, &hunter);
         Self::deposit_event(RawEvent::CancelHuntBounty(bounty_id, hunter));
         Ok(())
     }
@@ -76,11 +92,15 @@ impl<T: Trait> Module<T> {
             Error::<T>::NotHunter
         );
 
+        let bounty = Self::get_bounty(&bounty_id)?;
+        Self::settle_stream(bounty_id, &bounty)?;
         Self::remove_hunter_for_bounty(bounty_id);
 
         T::ReputationBuilder::add_behavior_score_by_behavior(
             &hunter,
+            Role::Hunter,
             &Behavior::BountyResolve(BountyResolveCollaborationResult::Fail),
+            &Self::bounty_context(bounty_id, &bounty, &Self::get_funder(&bounty)),
         );
 
         Self::deposit_event(RawEvent::Resign(bounty_id, hunter));
@@ -91,32 +111,45 @@ impl<T: Trait> Module<T> {
         Ok(())
     }
 
-    pub fn remark_bounty_funder_impl(
+    pub fn request_extension_impl(
         bounty_id: BountyId,
         hunter: T::AccountId,
-        _remark: BountyRemarkCollaborationResult,
+        extra_blocks: T::BlockNumber,
+        reason_digest: SdDigest,
     ) -> DispatchResult {
         ensure!(
-            Self::bounty_state_of(bounty_id) == BountyState::Resolved,
+            Self::bounty_state_of(bounty_id) == BountyState::Assigned,
             Error::<T>::InvalidState
         );
         ensure!(
             Self::hunted_for_bounty(&bounty_id) == hunter,
-            Error::<T>::NotHunter
+            Error::<T>::NotAssignee
         );
-
-        // remove hunter
-        Self::remove_hunters_for_bounty(bounty_id);
-
-        let bounty = Self::get_bounty(&bounty_id)?;
-        let funder = Self::get_funder(&bounty);
-
-        T::ReputationBuilder::add_behavior_score_by_behavior(
-            &funder,
-            &Behavior::BountyRemark(_remark),
+        ensure!(
+            !PendingExtension::<T>::contains_key(bounty_id),
+            Error::<T>::ExtensionPending
+        );
+        ensure!(
+            (ExtensionHistory::<T>::decode_len(bounty_id).unwrap_or(0) as u32)
+                < Self::max_extensions(),
+            Error::<T>::TooManyExtensions
         );
 
-        Self::deposit_event(RawEvent::HunterRemark(bounty_id, funder, _remark));
+        PendingExtension::<T>::insert(
+            bounty_id,
+            ExtensionRequest {
+                hunter: hunter.clone(),
+                extra_blocks,
+                reason_digest,
+                requested_at: frame_system::Module::<T>::block_number(),
+            },
+        );
+        Self::note_hunter_activity(bounty_id);
+        Self::deposit_event(RawEvent::ExtensionRequested(
+            bounty_id,
+            hunter,
+            extra_blocks,
+        ));
         Ok(())
     }
 }
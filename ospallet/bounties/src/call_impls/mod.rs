@@ -1,4 +1,8 @@
+mod batch;
 mod common;
 mod council;
 mod funder;
 mod hunter;
+mod remark;
+mod stream;
+mod template;
@@ -0,0 +1,244 @@
+use frame_support::{
+    dispatch::DispatchResult,
+    ensure,
+    storage::{IterableStorageDoubleMap, StorageDoubleMap, StorageMap},
+};
+use sp_runtime::traits::{Hash, Saturating};
+use sp_std::prelude::*;
+
+use opensquare_primitives::BountyId;
+use ospallet_reputation::{
+    Behavior, BountyRemarkCollaborationResult, BountyResolveCollaborationResult, ReputationBuilder,
+    Role,
+};
+
+use crate::types::{BountyOf, BountyState, RemarkRound, RemarkRoundOf, RemarkSlot};
+use crate::{Error, HunterBounties, Module, RawEvent, RemarkAgenda, RemarkRounds, Trait};
+
+impl<T: Trait> Module<T> {
+    /// Hash committed for a remark, revealed later with the remark and the salt.
+    pub fn remark_commitment(
+        bounty_id: BountyId,
+        who: &T::AccountId,
+        remark: BountyRemarkCollaborationResult,
+        salt: &[u8; 32],
+    ) -> T::Hash {
+        T::Hashing::hash_of(&(bounty_id, who, remark, salt))
+    }
+
+    /// Open the remark phase of a resolved bounty with the commitment of the funder.
+    pub fn open_remark_round(
+        bounty_id: BountyId,
+        funder: T::AccountId,
+        hunter: T::AccountId,
+        commitment: T::Hash,
+    ) {
+        let now = frame_system::Module::<T>::block_number();
+        let commit_end = now.saturating_add(Self::remark_commit_period());
+        let reveal_end = commit_end.saturating_add(Self::remark_reveal_period());
+
+        RemarkRounds::<T>::insert(
+            bounty_id,
+            RemarkRound {
+                funder: funder.clone(),
+                hunter,
+                funder_remark: RemarkSlot::Committed(commitment),
+                hunter_remark: RemarkSlot::Empty,
+                commit_end,
+                reveal_end,
+            },
+        );
+        RemarkAgenda::<T>::insert(commit_end, bounty_id, ());
+        RemarkAgenda::<T>::insert(reveal_end, bounty_id, ());
+        Self::deposit_event(RawEvent::RemarkCommitted(bounty_id, funder));
+    }
+
+    pub fn remark_bounty_funder_impl(
+        bounty_id: BountyId,
+        hunter: T::AccountId,
+        commitment: T::Hash,
+    ) -> DispatchResult {
+        ensure!(
+            Self::bounty_state_of(bounty_id) == BountyState::Resolved,
+            Error::<T>::InvalidState
+        );
+        // the round of a resolved bounty only ends after the remark window
+        let mut round = Self::remark_rounds(bounty_id).ok_or(Error::<T>::RemarkWindowClosed)?;
+        ensure!(round.hunter == hunter, Error::<T>::NotHunter);
+        let now = frame_system::Module::<T>::block_number();
+        ensure!(now < round.commit_end, Error::<T>::RemarkWindowClosed);
+        // hunter could only remark once
+        ensure!(
+            round.hunter_remark == RemarkSlot::Empty,
+            Error::<T>::AlreadyCommitted
+        );
+
+        // remove hunters, `HuntedForBounty` is kept for archive
+        Self::remove_applicants_for_bounty(bounty_id);
+        HunterBounties::<T>::remove(&hunter, bounty_id);
+
+        round.hunter_remark = RemarkSlot::Committed(commitment);
+        RemarkRounds::<T>::insert(bounty_id, round);
+        Self::deposit_event(RawEvent::RemarkCommitted(bounty_id, hunter));
+        Ok(())
+    }
+
+    pub fn reveal_remark_impl(
+        bounty_id: BountyId,
+        who: T::AccountId,
+        remark: BountyRemarkCollaborationResult,
+        salt: [u8; 32],
+    ) -> DispatchResult {
+        let mut round = match Self::remark_rounds(bounty_id) {
+            Some(round) => round,
+            None if Self::bounty_state_of(bounty_id) == BountyState::Resolved => {
+                return Err(Error::<T>::RemarkWindowClosed.into())
+            }
+            None => return Err(Error::<T>::RemarkNotOpen.into()),
+        };
+        let now = frame_system::Module::<T>::block_number();
+        let both_committed =
+            round.funder_remark != RemarkSlot::Empty && round.hunter_remark != RemarkSlot::Empty;
+        ensure!(
+            both_committed || now >= round.commit_end,
+            Error::<T>::RevealNotStarted
+        );
+
+        let commitment = Self::remark_commitment(bounty_id, &who, remark, &salt);
+        let slot = if who == round.funder {
+            &mut round.funder_remark
+        } else if who == round.hunter {
+            &mut round.hunter_remark
+        } else {
+            return Err(Error::<T>::NotRemarker.into());
+        };
+        match slot {
+            RemarkSlot::Committed(hash) => {
+                ensure!(*hash == commitment, Error::<T>::CommitmentMismatch)
+            }
+            RemarkSlot::Empty => return Err(Error::<T>::NotCommitted.into()),
+            RemarkSlot::Revealed(_) => return Err(Error::<T>::AlreadyRevealed.into()),
+        }
+        *slot = RemarkSlot::Revealed(remark);
+        Self::deposit_event(RawEvent::RemarkRevealed(bounty_id, who));
+
+        // an empty slot is never filled once revealing started
+        let all_revealed = match (&round.funder_remark, &round.hunter_remark) {
+            (RemarkSlot::Committed(_), _) | (_, RemarkSlot::Committed(_)) => false,
+            _ => true,
+        };
+        if all_revealed {
+            RemarkRounds::<T>::remove(bounty_id);
+            Self::finalize_remark_round(bounty_id, round);
+        } else {
+            RemarkRounds::<T>::insert(bounty_id, round);
+        }
+        Ok(())
+    }
+
+    /// Close the remark windows and finalize the remark rounds ending at `now`.
+    ///
+    /// Return the number of windows closed and the number of rounds finalized.
+    pub fn process_remark_agenda(now: T::BlockNumber) -> (u32, u32) {
+        let mut closed = 0;
+        let mut finalized = 0;
+        let bounty_ids = RemarkAgenda::<T>::drain_prefix(now)
+            .map(|(bounty_id, _)| bounty_id)
+            .collect::<Vec<_>>();
+        for bounty_id in bounty_ids {
+            let round = match Self::remark_rounds(bounty_id) {
+                Some(round) => round,
+                None => continue,
+            };
+            if round.commit_end == now {
+                Self::close_remark_window(bounty_id, &round);
+                closed += 1;
+            }
+            if round.reveal_end == now {
+                RemarkRounds::<T>::remove(bounty_id);
+                Self::finalize_remark_round(bounty_id, round);
+                finalized += 1;
+            }
+        }
+        (closed, finalized)
+    }
+
+    /// Clean up the hunters of a bounty whose hunter missed the remark window, the neutral
+    /// `DefaultRemark` will apply on the funder when the round is finalized.
+    fn close_remark_window(bounty_id: BountyId, round: &RemarkRoundOf<T>) {
+        if round.hunter_remark != RemarkSlot::Empty {
+            return;
+        }
+        // `HuntedForBounty` is kept for archive
+        Self::remove_applicants_for_bounty(bounty_id);
+        HunterBounties::<T>::remove(&round.hunter, bounty_id);
+        Self::deposit_event(RawEvent::RemarkMissed(bounty_id, round.hunter.clone()));
+    }
+
+    fn finalize_remark_round(bounty_id: BountyId, round: RemarkRoundOf<T>) {
+        let bounty = match Self::bounties(bounty_id) {
+            Some(bounty) => bounty,
+            None => return,
+        };
+        let default = Self::default_remark();
+        let funder_remark = round.funder_remark.revealed_or(default);
+        let hunter_remark = round.hunter_remark.revealed_or(default);
+
+        Self::_add_reputation(
+            bounty_id,
+            &bounty,
+            &round.funder,
+            &round.hunter,
+            funder_remark,
+            hunter_remark,
+        );
+
+        Self::deposit_event(RawEvent::FunderRemark(
+            bounty_id,
+            round.hunter,
+            funder_remark,
+        ));
+        Self::deposit_event(RawEvent::HunterRemark(
+            bounty_id,
+            round.funder,
+            hunter_remark,
+        ));
+    }
+
+    fn _add_reputation(
+        bounty_id: BountyId,
+        bounty: &BountyOf<T>,
+        funder: &T::AccountId,
+        hunter: &T::AccountId,
+        funder_remark: BountyRemarkCollaborationResult,
+        hunter_remark: BountyRemarkCollaborationResult,
+    ) {
+        let funder_context = Self::bounty_context(bounty_id, bounty, hunter);
+        let hunter_context = Self::bounty_context(bounty_id, bounty, funder);
+        // remarks are weighed before the resolve counts as a collaboration
+        T::ReputationBuilder::add_behavior_score_by_behavior(
+            funder,
+            Role::Funder,
+            &Behavior::BountyRemark(hunter_remark),
+            &funder_context,
+        );
+        T::ReputationBuilder::add_behavior_score_by_behavior(
+            hunter,
+            Role::Hunter,
+            &Behavior::BountyRemark(funder_remark),
+            &hunter_context,
+        );
+        T::ReputationBuilder::add_behavior_score_by_behavior(
+            funder,
+            Role::Funder,
+            &Behavior::BountyResolve(BountyResolveCollaborationResult::Success),
+            &funder_context,
+        );
+        T::ReputationBuilder::add_behavior_score_by_behavior(
+            hunter,
+            Role::Hunter,
+            &Behavior::BountyResolve(BountyResolveCollaborationResult::Success),
+            &hunter_context,
+        );
+    }
+}
@@ -0,0 +1,136 @@
+use frame_support::{
+    dispatch::DispatchResult,
+    ensure,
+    storage::StorageMap,
+    traits::{BalanceStatus, Get},
+};
+use sp_runtime::traits::{SaturatedConversion, Saturating, Zero};
+
+use opensquare_primitives::BountyId;
+use orml_traits::MultiReservableCurrency;
+
+use crate::types::{BountyOf, BountyState};
+use crate::{BalanceOf, Error, HuntedForBounty, Module, RawEvent, StreamOf, Streaming, Trait};
+
+impl<T: Trait> Module<T> {
+    pub fn set_streaming_impl(
+        bounty_id: BountyId,
+        funder: T::AccountId,
+        streaming: bool,
+    ) -> DispatchResult {
+        let bounty = Self::get_bounty(&bounty_id)?;
+        Self::check_funder(&funder, &bounty)?;
+        let state = Self::bounty_state_of(bounty_id);
+        ensure!(
+            (state == BountyState::Applying) || (state == BountyState::Accepted),
+            Error::<T>::InvalidState
+        );
+
+        if streaming {
+            Streaming::insert(bounty_id, ());
+        } else {
+            Streaming::remove(bounty_id);
+        }
+
+        Self::deposit_event(RawEvent::StreamingSet(bounty_id, streaming));
+        Ok(())
+    }
+
+    pub fn withdraw_vested_impl(bounty_id: BountyId, hunter: T::AccountId) -> DispatchResult {
+        let state = Self::bounty_state_of(bounty_id);
+        ensure!(
+            (state == BountyState::Assigned) || (state == BountyState::Submitted),
+            Error::<T>::InvalidState
+        );
+        ensure!(
+            Self::hunted_for_bounty(&bounty_id) == hunter,
+            Error::<T>::NotAssignee
+        );
+        ensure!(Streaming::contains_key(bounty_id), Error::<T>::NotStreaming);
+        let bounty = Self::get_bounty(&bounty_id)?;
+
+        let amount = Self::claimable_stream(bounty_id, &bounty);
+        ensure!(!amount.is_zero(), Error::<T>::NothingVested);
+        Self::pay_stream(bounty_id, &bounty, &hunter, amount)?;
+
+        StreamOf::<T>::mutate(bounty_id, |info| {
+            info.withdrawn = info.withdrawn.saturating_add(amount)
+        });
+        Self::note_hunter_activity(bounty_id);
+        Ok(())
+    }
+
+    /// Payment streamed to hunters, after the council fee is deducted.
+    pub fn stream_total(bounty: &BountyOf<T>) -> BalanceOf<T> {
+        let (_, locked) = Self::parse_payment(bounty);
+        locked.saturating_sub(T::CouncilFee::get() * locked)
+    }
+
+    /// Total amount already paid to hunters by streaming.
+    pub fn streamed(bounty_id: BountyId) -> BalanceOf<T> {
+        let info = Self::stream_of(bounty_id);
+        info.paid_before.saturating_add(info.withdrawn)
+    }
+
+    /// Vested but not withdrawn amount of the assigned hunter.
+    ///
+    /// The amount not paid to previous hunters vests linearly from `AssignedHeight` to `Deadline`.
+    pub fn claimable_stream(bounty_id: BountyId, bounty: &BountyOf<T>) -> BalanceOf<T> {
+        if !Streaming::contains_key(bounty_id) || !HuntedForBounty::<T>::contains_key(bounty_id) {
+            return Zero::zero();
+        }
+        let info = Self::stream_of(bounty_id);
+        let pool = Self::stream_total(bounty).saturating_sub(info.paid_before);
+
+        let now = frame_system::Module::<T>::block_number();
+        let start = Self::assigned_height(bounty_id);
+        let end = Self::deadline(bounty_id).unwrap_or(start);
+        let vested = if now >= end {
+            pool
+        } else {
+            let elapsed = now.saturating_sub(start).saturated_into::<u128>();
+            let duration = end.saturating_sub(start).saturated_into::<u128>();
+            (pool.saturated_into::<u128>().saturating_mul(elapsed) / duration).saturated_into()
+        };
+        vested.saturating_sub(info.withdrawn)
+    }
+
+    /// Pay the vested amount to the leaving hunter, the rest vests for the next hunter.
+    pub fn settle_stream(bounty_id: BountyId, bounty: &BountyOf<T>) -> DispatchResult {
+        if !Streaming::contains_key(bounty_id) || !HuntedForBounty::<T>::contains_key(bounty_id) {
+            return Ok(());
+        }
+        // the resolved hunter keeps the record but takes the rest of the payment on resolving
+        let state = Self::bounty_state_of(bounty_id);
+        ensure!(
+            (state == BountyState::Assigned) || (state == BountyState::Submitted),
+            Error::<T>::InvalidState
+        );
+        let hunter = Self::hunted_for_bounty(bounty_id);
+        let amount = Self::claimable_stream(bounty_id, bounty);
+        if !amount.is_zero() {
+            Self::pay_stream(bounty_id, bounty, &hunter, amount)?;
+        }
+        StreamOf::<T>::mutate(bounty_id, |info| {
+            info.paid_before = info
+                .paid_before
+                .saturating_add(info.withdrawn)
+                .saturating_add(amount);
+            info.withdrawn = Zero::zero();
+        });
+        Ok(())
+    }
+
+    fn pay_stream(
+        bounty_id: BountyId,
+        bounty: &BountyOf<T>,
+        hunter: &T::AccountId,
+        amount: BalanceOf<T>,
+    ) -> DispatchResult {
+        let (id, _) = Self::parse_payment(bounty);
+        let funder = Self::get_funder(bounty);
+        let _ = T::Currency::repatriate_reserved(id, &funder, hunter, amount, BalanceStatus::Free)?;
+        Self::deposit_event(RawEvent::StreamWithdrawn(bounty_id, hunter.clone(), amount));
+        Ok(())
+    }
+}
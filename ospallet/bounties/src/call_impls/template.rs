@@ -0,0 +1,201 @@
+use frame_support::{
+    dispatch::{DispatchError, DispatchResult},
+    ensure,
+    storage::{IterableStorageDoubleMap, StorageDoubleMap, StorageMap, StorageValue},
+};
+use sp_runtime::traits::{One, Saturating, Zero};
+use sp_std::{prelude::*, result};
+
+use opensquare_primitives::{BountyId, SdDigest};
+
+use crate::types::{
+    Bounty, BountyCategory, BountyMetaData, BountyTemplate, BountyTemplateOf, TemplateIndex,
+    TemplateSchedule,
+};
+use crate::{
+    BalanceOf, CurrencyIdOf, Error, Module, RawEvent, SpawnedCount, TemplateAgenda, TemplateCount,
+    TemplateSchedules, Templates, Trait,
+};
+
+const SEQUENCE_MARK: &[u8; 2] = b"{}";
+
+impl<T: Trait> Module<T> {
+    pub fn create_template_impl(
+        owner: T::AccountId,
+        currency_id: CurrencyIdOf<T>,
+        payment: BalanceOf<T>,
+        category: BountyCategory,
+        title_pattern: Vec<u8>,
+        digest: SdDigest,
+    ) -> DispatchResult {
+        ensure!(
+            title_pattern.len() <= Self::max_title_pattern_length() as usize,
+            Error::<T>::TitlePatternTooLong
+        );
+        let index = Self::template_count();
+        Templates::<T>::insert(
+            index,
+            BountyTemplate {
+                owner: owner.clone(),
+                currency_id,
+                payment,
+                category,
+                title_pattern,
+                digest,
+            },
+        );
+        TemplateCount::put(index.saturating_add(1));
+
+        Self::deposit_event(RawEvent::TemplateCreated(owner, index));
+        Ok(())
+    }
+
+    pub fn remove_template_impl(owner: T::AccountId, index: TemplateIndex) -> DispatchResult {
+        Self::check_template_owner(&owner, index)?;
+
+        Templates::<T>::remove(index);
+        TemplateSchedules::<T>::remove(index);
+        SpawnedCount::remove(index);
+
+        Self::deposit_event(RawEvent::TemplateRemoved(index));
+        Ok(())
+    }
+
+    pub fn instantiate_template_impl(owner: T::AccountId, index: TemplateIndex) -> DispatchResult {
+        let template = Self::check_template_owner(&owner, index)?;
+        Self::spawn_from_template(index, template)?;
+        Ok(())
+    }
+
+    pub fn schedule_template_impl(
+        owner: T::AccountId,
+        index: TemplateIndex,
+        period: Option<T::BlockNumber>,
+    ) -> DispatchResult {
+        Self::check_template_owner(&owner, index)?;
+
+        match period {
+            Some(period) => {
+                ensure!(!period.is_zero(), Error::<T>::InvalidPeriod);
+                let next_spawn = frame_system::Module::<T>::block_number().saturating_add(period);
+                TemplateSchedules::<T>::insert(index, TemplateSchedule { period, next_spawn });
+                TemplateAgenda::<T>::insert(next_spawn, index, ());
+            }
+            // stale agenda entries will be ignored
+            None => TemplateSchedules::<T>::remove(index),
+        }
+
+        Self::deposit_event(RawEvent::TemplateScheduled(index, period));
+        Ok(())
+    }
+
+    /// Spawn bounties for templates scheduled at `now`, a series stops when its bounty can't be created.
+    /// At most `MaxTemplatesPerBlock` templates are tried, the rest are carried over to the next block.
+    ///
+    /// Return the number of templates tried and carried over.
+    pub fn spawn_scheduled_templates(now: T::BlockNumber) -> (u32, u32) {
+        let (mut tried, mut carried) = (0, 0);
+        let indices = TemplateAgenda::<T>::drain_prefix(now)
+            .map(|(index, _)| index)
+            .collect::<Vec<_>>();
+        for index in indices {
+            let schedule = match Self::template_schedules(index) {
+                Some(schedule) if schedule.next_spawn == now => schedule,
+                _ => continue,
+            };
+            let template = match Self::templates(index) {
+                Some(template) => template,
+                None => continue,
+            };
+
+            if tried >= Self::max_templates_per_block() {
+                let next_spawn = now.saturating_add(One::one());
+                TemplateSchedules::<T>::insert(
+                    index,
+                    TemplateSchedule {
+                        period: schedule.period,
+                        next_spawn,
+                    },
+                );
+                TemplateAgenda::<T>::insert(next_spawn, index, ());
+                carried += 1;
+                continue;
+            }
+
+            tried += 1;
+            if Self::spawn_from_template(index, template).is_ok() {
+                let next_spawn = now.saturating_add(schedule.period);
+                TemplateSchedules::<T>::insert(
+                    index,
+                    TemplateSchedule {
+                        period: schedule.period,
+                        next_spawn,
+                    },
+                );
+                TemplateAgenda::<T>::insert(next_spawn, index, ());
+            } else {
+                TemplateSchedules::<T>::remove(index);
+                Self::deposit_event(RawEvent::TemplateStopped(index));
+            }
+        }
+        (tried, carried)
+    }
+
+    fn spawn_from_template(
+        index: TemplateIndex,
+        template: BountyTemplateOf<T>,
+    ) -> result::Result<BountyId, DispatchError> {
+        let sequence = Self::spawned_count(index);
+        let title = Self::fill_title_pattern(&template.title_pattern, sequence);
+        let bounty = Bounty::V2(BountyMetaData {
+            owner: template.owner.clone(),
+            currency_id: template.currency_id,
+            payment: template.payment,
+            category: template.category,
+            title,
+            digest: template.digest,
+        });
+
+        let bounty_id = Self::create_bounty_impl(template.owner, bounty)?;
+        SpawnedCount::insert(index, sequence.saturating_add(1));
+        Self::deposit_event(RawEvent::TemplateSpawned(index, bounty_id));
+        Ok(bounty_id)
+    }
+
+    fn check_template_owner(
+        owner: &T::AccountId,
+        index: TemplateIndex,
+    ) -> result::Result<BountyTemplateOf<T>, DispatchError> {
+        let template = Self::templates(index).ok_or(Error::<T>::TemplateNotExisted)?;
+        ensure!(&template.owner == owner, Error::<T>::NotTemplateOwner);
+        Ok(template)
+    }
+
+    fn fill_title_pattern(pattern: &[u8], sequence: u32) -> Vec<u8> {
+        match pattern
+            .windows(SEQUENCE_MARK.len())
+            .position(|w| w == &SEQUENCE_MARK[..])
+        {
+            Some(pos) => {
+                let mut title = pattern[..pos].to_vec();
+                title.extend_from_slice(Self::u32_to_ascii(sequence).as_slice());
+                title.extend_from_slice(&pattern[pos + SEQUENCE_MARK.len()..]);
+                title
+            }
+            None => pattern.to_vec(),
+        }
+    }
+
+    fn u32_to_ascii(mut n: u32) -> Vec<u8> {
+        let mut digits = Vec::new();
+        loop {
+            digits.push(b'0' + (n % 10) as u8);
+            n /= 10;
+            if n == 0 {
+                break;
+            }
+        }
+        digits.reverse();
+        digits
+    }
+}
@@ -2,8 +2,11 @@
 
 use frame_support::traits::Get;
 use frame_support::{
-    decl_error, decl_event, decl_module, decl_storage, dispatch::DispatchResult,
+    decl_error, decl_event, decl_module, decl_storage,
+    dispatch::{DispatchError, DispatchResult},
     traits::EnsureOrigin,
+    transactional,
+    weights::Weight,
 };
 use frame_system::ensure_signed;
 use sp_runtime::{
@@ -12,17 +15,26 @@ use sp_runtime::{
 };
 use sp_std::{marker::PhantomData, prelude::*};
 
-use opensquare_primitives::BountyId;
+use opensquare_primitives::{BountyId, BountyIndex, SdDigest, BLOCKS_PER_DAY};
 // orml
 use orml_traits::{MultiCurrency, MultiReservableCurrency};
 
-use crate::types::{Bounty, BountyOf, BountyState, CloseReason, HunterBountyState};
+use crate::types::{
+    Bounty, BountySummaryOf, BountyTemplateOf, CloseReason, DeclineReason, ExtensionRequestOf,
+    HunterBountyState, Releases, RemarkRoundOf, StreamInfo, TemplateIndex, TemplateSchedule,
+};
+pub use crate::types::{BountyCategory, BountyOf, BountyState};
 
 use ospallet_mining::MiningPowerBuilder;
 use ospallet_reputation::{BountyRemarkCollaborationResult, ReputationBuilder};
 
+#[cfg(feature = "runtime-benchmarks")]
+mod benchmarking;
 mod call_impls;
 mod types;
+mod weights;
+
+pub use weights::WeightInfo;
 
 pub type BalanceOf<T> =
     <<T as Trait>::Currency as MultiCurrency<<T as frame_system::Trait>::AccountId>>::Balance;
@@ -57,14 +69,39 @@ where
     }
 }
 
+/// Sequential BountyId determiner.
+///
+/// BountyId is derived from the global bounty counter, so the id is readable as a small number
+/// and never collides.
+///
+/// Formula: `BountyId::from_low_u64_be(bounty_count)`
+pub struct SequentialBountyIdDeterminer<T: Trait>(PhantomData<T>);
+
+impl<T: Trait> BountyIdFor<T::AccountId> for SequentialBountyIdDeterminer<T> {
+    fn bounty_id_for(_origin: &T::AccountId) -> BountyId {
+        BountyId::from_low_u64_be(Module::<T>::bounty_count() as u64)
+    }
+}
+
 pub trait BountyResolved<T: Trait> {
-    fn after_bounty_resolved(_bounty: &BountyOf<T>) {}
+    fn after_bounty_resolved(_bounty: &BountyOf<T>, _hunter: &T::AccountId) {}
+
+    /// Weight of `after_bounty_resolved`, it's charged by resolving a bounty.
+    fn after_bounty_resolved_weight() -> Weight {
+        0
+    }
 }
 
 #[impl_trait_for_tuples::impl_for_tuples(30)]
 impl<T: Trait> BountyResolved<T> for Tuple {
-    fn after_bounty_resolved(_bounty: &BountyOf<T>) {
-        for_tuples!( #( Tuple::after_bounty_resolved(_bounty); )* );
+    fn after_bounty_resolved(_bounty: &BountyOf<T>, _hunter: &T::AccountId) {
+        for_tuples!( #( Tuple::after_bounty_resolved(_bounty, _hunter); )* );
+    }
+
+    fn after_bounty_resolved_weight() -> Weight {
+        let mut weight: Weight = 0;
+        for_tuples!( #( weight = weight.saturating_add(Tuple::after_bounty_resolved_weight()); )* );
+        weight
     }
 }
 
@@ -87,6 +124,21 @@ pub trait Trait: frame_system::Trait {
     type ReputationBuilder: ReputationBuilder<Self::AccountId>;
 
     type MiningPowerBuilder: MiningPowerBuilder<Self::AccountId>;
+
+    /// The currency used to reserve the reap deposit.
+    type GetNativeCurrencyId: Get<CurrencyIdOf<Self>>;
+
+    /// Deposit reserved from the funder when creating a bounty, refunded when the bounty is reaped.
+    type ReapDeposit: Get<BalanceOf<Self>>;
+
+    /// Whether tips on resolution bring mining power, the council fee is charged on the tip if so.
+    type TipMining: Get<bool>;
+
+    /// Whether tips on resolution bring reputation to the hunter.
+    type TipReputation: Get<bool>;
+
+    /// Weight information for extrinsics in this pallet.
+    type WeightInfo: WeightInfo;
 }
 
 decl_error! {
@@ -108,12 +160,72 @@ decl_error! {
         NotHunter,
         /// not assignee of this bounty
         NotAssignee,
+        /// bounty is not finished or still in retention period
+        NotReapable,
+        /// not the proposed new owner of this bounty
+        NotPendingOwner,
+        /// the proposed owner already owns this bounty
+        AlreadyOwner,
+        /// the assigned hunter could not own this bounty
+        OwnerIsHunter,
+        /// this hunter is declined by the funder
+        Declined,
+        /// beyond limit of max applicants of this bounty
+        TooManyApplicants,
+        /// an extension request is waiting for the funder
+        ExtensionPending,
+        /// no extension request for this bounty
+        NoExtensionRequest,
+        /// beyond limit of examined extension requests of this bounty
+        TooManyExtensions,
+        /// the deadline of the assigned bounty is not passed
+        NotOutdated,
+        /// template not existed
+        TemplateNotExisted,
+        /// not owner of this template
+        NotTemplateOwner,
+        /// spawn period should not be zero
+        InvalidPeriod,
+        /// title pattern of the template is too long
+        TitlePatternTooLong,
+        /// some prerequisites of this bounty are not resolved
+        PrerequisitesUnresolved,
+        /// prerequisite not existed, duplicated, beyond limit or not created before the bounty
+        InvalidPrerequisites,
+        /// a prerequisite already has `MaxDependents` dependents
+        TooManyDependents,
+        /// `ApplicantsLimit` hunters have been declined for this bounty
+        TooManyDeclined,
+        /// payment of this bounty is not streamed
+        NotStreaming,
+        /// no vested payment to withdraw
+        NothingVested,
+        /// beyond limit of max items in a batch
+        TooManyItems,
+        /// the bounty is not in the remark phase
+        RemarkNotOpen,
+        /// the remark window of the resolved bounty is closed
+        RemarkWindowClosed,
+        /// remarks could be revealed after both sides committed or the commit period is over
+        RevealNotStarted,
+        /// neither the funder nor the hunter of the remark phase
+        NotRemarker,
+        /// the remark has been committed
+        AlreadyCommitted,
+        /// no remark committed to reveal
+        NotCommitted,
+        /// the remark has been revealed
+        AlreadyRevealed,
+        /// the revealed remark and salt don't match the commitment
+        CommitmentMismatch,
     }
 }
 decl_event!(
     pub enum Event<T> where
         <T as frame_system::Trait>::AccountId,
-        Balance = BalanceOf<T>
+        <T as frame_system::Trait>::BlockNumber,
+        Balance = BalanceOf<T>,
+        CurrencyId = CurrencyIdOf<T>
     {
         ApplyBounty(AccountId, BountyId),
         Accept(BountyId),
@@ -123,11 +235,36 @@ decl_event!(
         HuntBounty(BountyId, AccountId),
         CancelHuntBounty(BountyId, AccountId),
         AssignBounty(BountyId, AccountId),
+        UnassignBounty(BountyId, AccountId),
         Submit(BountyId),
         Resign(BountyId, AccountId),
         Resolve(BountyId),
+        Tip(BountyId, AccountId, CurrencyId, Balance),
+        RemarkCommitted(BountyId, AccountId),
+        RemarkRevealed(BountyId, AccountId),
+        RemarkMissed(BountyId, AccountId),
         FunderRemark(BountyId, AccountId, BountyRemarkCollaborationResult),
         HunterRemark(BountyId, AccountId, BountyRemarkCollaborationResult),
+        Reaped(BountyId, AccountId),
+        OwnershipProposed(BountyId, AccountId),
+        OwnershipTransferred(BountyId, AccountId, AccountId),
+        DeclineHunter(BountyId, AccountId, DeclineReason),
+        ShortlistHunter(BountyId, AccountId, bool),
+        MaxApplicantsSet(BountyId, Option<u32>),
+        ExtensionRequested(BountyId, AccountId, BlockNumber),
+        ExtensionExamined(BountyId, bool, BlockNumber),
+        TemplateCreated(AccountId, TemplateIndex),
+        TemplateRemoved(TemplateIndex),
+        TemplateScheduled(TemplateIndex, Option<BlockNumber>),
+        TemplateSpawned(TemplateIndex, BountyId),
+        TemplateStopped(TemplateIndex),
+        PrerequisitesSet(BountyId, Vec<BountyId>),
+        StreamingSet(BountyId, bool),
+        StreamWithdrawn(BountyId, AccountId, Balance),
+        BatchItemSucceeded(u32),
+        BatchItemFailed(u32, DispatchError),
+        /// dependent, prerequisite, funder of the dependent
+        PrerequisiteFailed(BountyId, BountyId, AccountId),
     }
 );
 decl_storage! {
@@ -135,29 +272,131 @@ decl_storage! {
         /// Bounties basic info of a bounty_id
         pub Bounties get(fn bounties): map hasher(identity)
             BountyId => Option<Bounty<T::AccountId, CurrencyIdOf<T>, BalanceOf<T>>>;
+        /// Count of created bounties, also the index of next bounty
+        pub BountyCount get(fn bounty_count): BountyIndex;
+        /// BountyId of a sequential bounty index
+        pub BountyIdByIndex get(fn bounty_id_by_index): map hasher(twox_64_concat)
+            BountyIndex => Option<BountyId>;
+        /// Sequential index of a bounty_id
+        pub BountyIndexOf get(fn bounty_index_of): map hasher(identity) BountyId => Option<BountyIndex>;
         /// Record bounties of an accountid
         pub BountiesOf get(fn bounties_of): map hasher(blake2_128_concat)
             T::AccountId => Vec<BountyId>;
+        /// Proposed new owner of a bounty, waiting for acceptance
+        pub PendingOwner get(fn pending_owner): map hasher(identity) BountyId => Option<T::AccountId>;
         /// Bounty state of a bounty_id
         pub BountyStateOf get(fn bounty_state_of): map hasher(identity) BountyId => BountyState;
+        /// Index of bounties in a state, maintained by `change_state`
+        pub BountiesByState get(fn bounties_by_state):
+            double_map hasher(twox_64_concat) BountyState, hasher(identity) BountyId => ();
+        /// Index of bounties in a category
+        pub BountiesByCategory get(fn bounties_by_category):
+            double_map hasher(twox_64_concat) BountyCategory, hasher(identity) BountyId => ();
+
+        /// Bounties should be resolved before this bounty could be hunted or assigned
+        pub Prerequisites get(fn prerequisites): map hasher(identity) BountyId => Vec<BountyId>;
+        /// Bounties depend on this bounty
+        pub Dependents get(fn dependents): map hasher(identity) BountyId => Vec<BountyId>;
+        /// Prerequisites closed or rejected, the funder should reset prerequisites
+        pub BrokenPrerequisites get(fn broken_prerequisites): map hasher(identity) BountyId => Vec<BountyId>;
+        pub MaxPrerequisites get(fn max_prerequisites): u32 = 8;
+        /// dependents a bounty could have, it bounds the walk when the bounty is closed or rejected
+        pub MaxDependents get(fn max_dependents): u32 = 32;
+
+        /// Count of created templates, also the index of next template
+        pub TemplateCount get(fn template_count): TemplateIndex;
+        /// Bounty templates
+        pub Templates get(fn templates): map hasher(twox_64_concat)
+            TemplateIndex => Option<BountyTemplateOf<T>>;
+        /// Count of bounties spawned from a template
+        pub SpawnedCount get(fn spawned_count): map hasher(twox_64_concat) TemplateIndex => u32;
+        /// Spawning schedule of a template
+        pub TemplateSchedules get(fn template_schedules): map hasher(twox_64_concat)
+            TemplateIndex => Option<TemplateSchedule<T::BlockNumber>>;
+        /// Templates to spawn at a block, entries not matching `TemplateSchedules` are ignored
+        TemplateAgenda get(fn template_agenda):
+            double_map hasher(twox_64_concat) T::BlockNumber, hasher(twox_64_concat) TemplateIndex => ();
+        /// templates spawned in a block at most, the rest are carried over to the next block
+        pub MaxTemplatesPerBlock get(fn max_templates_per_block): u32 = 16;
+        /// length of a template title pattern at most
+        pub MaxTitlePatternLength get(fn max_title_pattern_length): u32 = 256;
 
         pub ApprovedHeight get(fn approved_height): map hasher(identity) BountyId => T::BlockNumber;
         pub AssignedHeight get(fn assigned_height): map hasher(identity) BountyId => T::BlockNumber;
+        /// height of the last action of the assigned hunter, inactivity is measured from it
+        pub LastActivityHeight get(fn last_activity_height): map hasher(identity) BountyId => T::BlockNumber;
+        /// height before which the assigned hunter should submit the work
+        pub Deadline get(fn deadline): map hasher(identity) BountyId => Option<T::BlockNumber>;
+        /// extension request waiting for the funder's approval
+        pub PendingExtension get(fn pending_extension): map hasher(identity)
+            BountyId => Option<ExtensionRequestOf<T>>;
+        /// examined extension requests of a bounty, with whether approved, at most `MaxExtensions`
+        pub ExtensionHistory get(fn extension_history): map hasher(identity)
+            BountyId => Vec<(ExtensionRequestOf<T>, bool)>;
+        /// payment of these bounties vests to the assigned hunter block by block
+        pub Streaming get(fn streaming): map hasher(identity) BountyId => ();
+        pub StreamOf get(fn stream_of): map hasher(identity) BountyId => StreamInfo<BalanceOf<T>>;
+        /// height when the bounty reached a terminal state
+        pub FinishedHeight get(fn finished_height): map hasher(identity) BountyId => T::BlockNumber;
+
+        /// reap deposit reserved from the funder for a bounty
+        pub ReapDepositOf get(fn reap_deposit_of): map hasher(identity) BountyId => BalanceOf<T>;
+        /// summary of reaped bounties
+        pub ArchivedBounties get(fn archived_bounties): map hasher(identity)
+            BountyId => Option<BountySummaryOf<T>>;
 
         /// mark this bounty has been hunting by who
         pub HuntingForBounty get(fn hunting_for_bounty):
             double_map hasher(identity) BountyId, hasher(blake2_128_concat) T::AccountId => bool;
+        /// hunters shortlisted by the funder
+        pub Shortlisted get(fn shortlisted):
+            double_map hasher(identity) BountyId, hasher(blake2_128_concat) T::AccountId => ();
+        /// hunters declined by the funder, they could not hunt this bounty again
+        pub DeclinedHunters get(fn declined_hunters):
+            double_map hasher(identity) BountyId, hasher(blake2_128_concat) T::AccountId => Option<DeclineReason>;
+        /// number of hunters declined for a bounty, at most `ApplicantsLimit`
+        pub DeclinedCount get(fn declined_count): map hasher(identity) BountyId => u32;
+        /// max number of hunters for a bounty, `ApplicantsLimit` if not set
+        pub MaxApplicants get(fn max_applicants): map hasher(identity) BountyId => Option<u32>;
+        /// upper bound of hunters for any bounty, it bounds the cost of removing hunters
+        pub ApplicantsLimit get(fn applicants_limit): u32 = 64;
         /// record a hunted bounty has been doing by who(single hunter)
         HuntedForBounty get(fn hunted_for_bounty): map hasher(identity) BountyId => T::AccountId;
 
         /// record bounties for a hunter, include hunting and hunted(in processing)
         pub HunterBounties get(fn hunter_bounties):
             double_map hasher(blake2_128_concat) T::AccountId, hasher(identity) BountyId => Option<HunterBountyState>;
+        /// count of bounties resolved by an account as the hunter, per category
+        pub ResolvedCount get(fn resolved_count):
+            double_map hasher(blake2_128_concat) T::AccountId, hasher(twox_64_concat) BountyCategory => u32;
 
         pub MaxHoldingBounties get(fn max_holding_bounties): u32 = 10;
+        pub MaxBatchSize get(fn max_batch_size): u32 = 32;
         pub OutdatedHeight get(fn outdated_height): T::BlockNumber = 1000.saturated_into();
+        /// approved extensions a hunter could have for a bounty without reputation loss
+        pub MaxFreeExtensions get(fn max_free_extensions): u32 = 2;
+        /// examined extension requests a bounty could have, it bounds `ExtensionHistory`
+        pub MaxExtensions get(fn max_extensions): u32 = 16;
+        /// blocks since the last action of the assigned hunter after which the hunter is treated as inactive
+        pub InactivityThreshold get(fn inactivity_threshold): T::BlockNumber = (7 * BLOCKS_PER_DAY).saturated_into();
+        /// blocks a finished bounty is kept before it could be reaped
+        pub RetentionPeriod get(fn retention_period): T::BlockNumber = (30 * BLOCKS_PER_DAY).saturated_into();
+
+        /// commit-reveal remark phase of resolved bounties
+        pub RemarkRounds get(fn remark_rounds): map hasher(identity) BountyId => Option<RemarkRoundOf<T>>;
+        /// Remark windows to close and phases to finalize at a block, entries not matching `RemarkRounds` are ignored
+        RemarkAgenda get(fn remark_agenda):
+            double_map hasher(twox_64_concat) T::BlockNumber, hasher(identity) BountyId => ();
+        /// blocks after the resolution in which both sides commit their remarks, the hunter missing it is cleaned up
+        pub RemarkCommitPeriod get(fn remark_commit_period): T::BlockNumber = (3 * BLOCKS_PER_DAY).saturated_into();
+        /// blocks after the commit period in which both sides reveal their remarks
+        pub RemarkRevealPeriod get(fn remark_reveal_period): T::BlockNumber = (3 * BLOCKS_PER_DAY).saturated_into();
+        /// remark applied for a side not revealing in time
+        pub DefaultRemark get(fn default_remark): BountyRemarkCollaborationResult = BountyRemarkCollaborationResult::Fine;
 
         pub CurrencyRatios get(fn currency_ratios) config(): map hasher(blake2_128_concat) CurrencyIdOf<T> => u128;
+
+        StorageVersion get(fn storage_version): Releases;
     }
         add_extra_genesis {
             config(dummy): u32;
@@ -174,22 +413,50 @@ decl_module! {
         type Error = Error<T>;
 
         fn deposit_event() = default;
+
+        fn on_initialize(now: T::BlockNumber) -> Weight {
+            let (spawned, carried) = Self::spawn_scheduled_templates(now);
+            let (closed, finalized) = Self::process_remark_agenda(now);
+            // remarks and resolutions of both sides are 4 behaviors
+            let behaviors = finalized.saturating_mul(4);
+            T::WeightInfo::spawn_template().saturating_mul(spawned as Weight)
+                .saturating_add(T::ReputationBuilder::behavior_hooks_weight().saturating_mul(behaviors as Weight))
+                .saturating_add(T::DbWeight::get().reads_writes(3, 2).saturating_mul(carried as Weight))
+                .saturating_add(T::WeightInfo::close_remark_window(Self::applicants_limit()).saturating_mul(closed as Weight))
+                .saturating_add(T::WeightInfo::finalize_remark_round().saturating_mul(finalized as Weight))
+        }
+
+        fn on_runtime_upgrade() -> Weight {
+            let mut weight: Weight = 0;
+            if Self::storage_version() == Releases::V1_0_0 {
+                let indexed = Self::build_state_index();
+                StorageVersion::put(Releases::V2_0_0);
+                weight = weight.saturating_add(T::DbWeight::get().reads_writes(indexed + 1, indexed + 1));
+            }
+            if Self::storage_version() == Releases::V2_0_0 {
+                let upgraded = Self::upgrade_bounties();
+                StorageVersion::put(Releases::V3_0_0);
+                weight = weight.saturating_add(T::DbWeight::get().reads_writes(upgraded * 4 + 1, upgraded * 3 + 1));
+            }
+            weight
+        }
+
         // funder call
-        #[weight = 0]
+        #[weight = T::WeightInfo::create_bounty()]
         fn create_bounty(origin, bounty: Bounty<T::AccountId, CurrencyIdOf<T>, BalanceOf<T>>) -> DispatchResult {
             let who = ensure_signed(origin)?;
             Self::create_bounty_impl(who, bounty)?;
             Ok(())
         }
 
-        #[weight = 0]
+        #[weight = T::WeightInfo::close_bounty(Self::applicants_limit(), Self::max_dependents())]
         fn close_bounty(origin, bounty_id: BountyId) -> DispatchResult {
             let who = ensure_signed(origin)?;
             Self::close_bounty_impl(who, bounty_id)?;
             Ok(())
         }
 
-        #[weight = 0]
+        #[weight = T::WeightInfo::assign_bounty()]
         fn assign_bounty(origin, bounty_id: BountyId, assign_to: <T::Lookup as StaticLookup>::Source) -> DispatchResult {
             let funder = ensure_signed(origin)?;
             let assign_to = T::Lookup::lookup(assign_to)?;
@@ -197,58 +464,821 @@ decl_module! {
             Self::assign_bounty_impl(bounty_id, funder, assign_to)
         }
 
-        #[weight = 0]
-        fn resolve_bounty_and_remark(origin, bounty_id: BountyId, remark: BountyRemarkCollaborationResult) -> DispatchResult {
+        /// resolve the bounty, `tip` is paid from funder's free balance to the hunter besides the payment
+        ///
+        /// `remark_commitment` is the hash from `remark_commitment` for the remark on the hunter, the remark is revealed
+        /// by `reveal_remark` later
+        #[weight = T::WeightInfo::resolve_bounty_and_remark().saturating_add(T::BountyResolved::after_bounty_resolved_weight()).saturating_add(T::ReputationBuilder::behavior_hooks_weight())]
+        #[transactional]
+        fn resolve_bounty_and_remark(origin, bounty_id: BountyId, remark_commitment: T::Hash, tip: Option<(CurrencyIdOf<T>, BalanceOf<T>)>) -> DispatchResult {
             let who = ensure_signed(origin)?;
-            Self::resolve_bounty_and_remark_impl(bounty_id, who, remark)?;
+            Self::resolve_bounty_and_remark_impl(bounty_id, who, remark_commitment, tip)?;
             Ok(())
         }
 
         // council call
         /// council accept or reject a bounty
-        #[weight = 0]
+        #[weight = T::WeightInfo::examine_bounty(Self::max_dependents())]
         fn examine_bounty(origin, bounty_id: BountyId, accepted: bool) -> DispatchResult {
             T::CouncilOrigin::ensure_origin(origin)?;
             Self::examine_bounty_impl(bounty_id, accepted)?;
             Ok(())
         }
 
-        #[weight = 0]
+        /// an assigned bounty past its deadline is closed as outdated, and its hunter loses reputation
+        #[weight = T::WeightInfo::force_close_bounty(Self::applicants_limit(), Self::max_dependents()).saturating_add(T::ReputationBuilder::behavior_hooks_weight())]
         fn force_close_bounty(origin, bounty_id: BountyId, reason: CloseReason) -> DispatchResult {
             T::CouncilOrigin::ensure_origin(origin)?;
             Self::force_close_bounty_impl(bounty_id, reason)
         }
 
         // hunter call
-        #[weight = 0]
+        #[weight = T::WeightInfo::hunt_bounty(Self::max_holding_bounties(), Self::applicants_limit())]
         fn hunt_bounty(origin, bounty_id: BountyId) -> DispatchResult {
             let who = ensure_signed(origin)?;
             Self::hunt_bounty_impl(bounty_id, who)
         }
 
-        #[weight = 0]
+        #[weight = T::WeightInfo::submit_bounty()]
         fn submit_bounty(origin, bounty_id: BountyId) -> DispatchResult {
             let hunter = ensure_signed(origin)?;
             Self::submit_bounty_impl(bounty_id, hunter)
         }
 
-        #[weight = 0]
+        #[weight = T::WeightInfo::cancel_hunt_bounty()]
         fn cancel_hunt_bounty(origin, bounty_id: BountyId) -> DispatchResult {
             let hunter = ensure_signed(origin)?;
             Self::cancel_bounty_hunting_impl(bounty_id, hunter)
         }
 
-        #[weight = 0]
+        #[weight = T::WeightInfo::resign_from_bounty().saturating_add(T::ReputationBuilder::behavior_hooks_weight())]
         fn resign_from_bounty(origin, bounty_id: BountyId) -> DispatchResult {
             let hunter = ensure_signed(origin)?;
             Self::resign_from_bounty_impl(bounty_id, hunter)
         }
 
-        #[weight = 0]
-        fn remark_bounty_funder(origin, bounty_id: BountyId, remark: BountyRemarkCollaborationResult) -> DispatchResult {
+        /// commit the hash from `remark_commitment` for the remark on the funder
+        #[weight = T::WeightInfo::remark_bounty_funder(Self::applicants_limit())]
+        fn remark_bounty_funder(origin, bounty_id: BountyId, commitment: T::Hash) -> DispatchResult {
+            let hunter = ensure_signed(origin)?;
+            Self::remark_bounty_funder_impl(bounty_id, hunter, commitment)
+        }
+
+        // later calls are appended to keep the indices of the calls above
+        /// prune a finished bounty after retention period, anyone could call it
+        #[weight = T::WeightInfo::reap_bounty(Self::max_prerequisites(), Self::applicants_limit())]
+        fn reap_bounty(origin, bounty_id: BountyId) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+            Self::reap_bounty_impl(bounty_id, who)
+        }
+
+        /// propose a new owner for the bounty, it takes effect after the new owner accept it
+        #[weight = T::WeightInfo::propose_bounty_owner()]
+        fn propose_bounty_owner(origin, bounty_id: BountyId, new_owner: <T::Lookup as StaticLookup>::Source) -> DispatchResult {
+            let funder = ensure_signed(origin)?;
+            let new_owner = T::Lookup::lookup(new_owner)?;
+
+            Self::propose_bounty_owner_impl(bounty_id, funder, new_owner)
+        }
+
+        #[weight = T::WeightInfo::accept_bounty_ownership()]
+        fn accept_bounty_ownership(origin, bounty_id: BountyId) -> DispatchResult {
+            let new_owner = ensure_signed(origin)?;
+            Self::accept_bounty_ownership_impl(bounty_id, new_owner)
+        }
+
+        /// decline a hunter, the hunter could not hunt this bounty again
+        #[weight = T::WeightInfo::decline_hunter()]
+        fn decline_hunter(origin, bounty_id: BountyId, hunter: <T::Lookup as StaticLookup>::Source, reason: DeclineReason) -> DispatchResult {
+            let funder = ensure_signed(origin)?;
+            let hunter = T::Lookup::lookup(hunter)?;
+
+            Self::decline_hunter_impl(bounty_id, funder, hunter, reason)
+        }
+
+        #[weight = T::WeightInfo::shortlist_hunter()]
+        fn shortlist_hunter(origin, bounty_id: BountyId, hunter: <T::Lookup as StaticLookup>::Source, shortlisted: bool) -> DispatchResult {
+            let funder = ensure_signed(origin)?;
+            let hunter = T::Lookup::lookup(hunter)?;
+
+            Self::shortlist_hunter_impl(bounty_id, funder, hunter, shortlisted)
+        }
+
+        /// set max number of hunters for the bounty, `None` for no limit
+        #[weight = T::WeightInfo::set_max_applicants()]
+        fn set_max_applicants(origin, bounty_id: BountyId, max: Option<u32>) -> DispatchResult {
+            let funder = ensure_signed(origin)?;
+            Self::set_max_applicants_impl(bounty_id, funder, max)
+        }
+
+        /// return an assigned bounty to accepted, the hunter keeps hunting it
+        #[weight = T::WeightInfo::unassign_bounty().saturating_add(T::ReputationBuilder::behavior_hooks_weight())]
+        fn unassign_bounty(origin, bounty_id: BountyId) -> DispatchResult {
+            let funder = ensure_signed(origin)?;
+            Self::unassign_bounty_impl(bounty_id, funder)
+        }
+
+        /// ask the funder for more time to finish the assigned bounty
+        #[weight = T::WeightInfo::request_extension()]
+        fn request_extension(origin, bounty_id: BountyId, extra_blocks: T::BlockNumber, reason_digest: SdDigest) -> DispatchResult {
             let hunter = ensure_signed(origin)?;
-            Self::remark_bounty_funder_impl(bounty_id, hunter, remark)
+            Self::request_extension_impl(bounty_id, hunter, extra_blocks, reason_digest)
+        }
+
+        /// approve or reject the pending extension request
+        #[weight = T::WeightInfo::approve_extension().saturating_add(T::ReputationBuilder::behavior_hooks_weight())]
+        fn approve_extension(origin, bounty_id: BountyId, approved: bool) -> DispatchResult {
+            let funder = ensure_signed(origin)?;
+            Self::approve_extension_impl(bounty_id, funder, approved)
+        }
+
+        /// create a template for bounties posted repeatedly
+        #[weight = T::WeightInfo::create_template()]
+        fn create_template(
+            origin,
+            currency_id: CurrencyIdOf<T>,
+            payment: BalanceOf<T>,
+            category: BountyCategory,
+            title_pattern: Vec<u8>,
+            digest: SdDigest
+        ) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+            Self::create_template_impl(who, currency_id, payment, category, title_pattern, digest)
+        }
+
+        #[weight = T::WeightInfo::remove_template()]
+        fn remove_template(origin, index: TemplateIndex) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+            Self::remove_template_impl(who, index)
+        }
+
+        /// create a bounty from the template now
+        #[weight = T::WeightInfo::instantiate_template()]
+        fn instantiate_template(origin, index: TemplateIndex) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+            Self::instantiate_template_impl(who, index)
+        }
+
+        /// spawn a bounty from the template every `period` blocks, `None` to stop
+        #[weight = T::WeightInfo::schedule_template()]
+        fn schedule_template(origin, index: TemplateIndex, period: Option<T::BlockNumber>) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+            Self::schedule_template_impl(who, index, period)
         }
 
+        /// set prerequisites of the bounty before it is assigned, empty to remove
+        #[weight = T::WeightInfo::set_prerequisites(prerequisites.len() as u32)]
+        fn set_prerequisites(origin, bounty_id: BountyId, prerequisites: Vec<BountyId>) -> DispatchResult {
+            let funder = ensure_signed(origin)?;
+            Self::set_prerequisites_impl(bounty_id, funder, prerequisites)
+        }
+
+        /// stream the payment to the assigned hunter linearly until the deadline
+        #[weight = T::WeightInfo::set_streaming()]
+        fn set_streaming(origin, bounty_id: BountyId, streaming: bool) -> DispatchResult {
+            let funder = ensure_signed(origin)?;
+            Self::set_streaming_impl(bounty_id, funder, streaming)
+        }
+
+        /// withdraw the vested payment of a streaming bounty
+        #[weight = T::WeightInfo::withdraw_vested()]
+        fn withdraw_vested(origin, bounty_id: BountyId) -> DispatchResult {
+            let hunter = ensure_signed(origin)?;
+            Self::withdraw_vested_impl(bounty_id, hunter)
+        }
+
+        /// create bounties in a batch, `atomic` decides whether a failed item reverts the whole batch
+        #[weight = T::WeightInfo::create_bounty().saturating_mul(bounties.len() as Weight)]
+        fn batch_create_bounties(origin, bounties: Vec<Bounty<T::AccountId, CurrencyIdOf<T>, BalanceOf<T>>>, atomic: bool) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+            Self::run_batch(bounties, atomic, |bounty| {
+                Self::create_bounty_impl(who.clone(), bounty).map(|_| ())
+            })
+        }
+
+        #[weight = T::WeightInfo::close_bounty(Self::applicants_limit(), Self::max_dependents()).saturating_mul(bounty_ids.len() as Weight)]
+        fn batch_close_bounties(origin, bounty_ids: Vec<BountyId>, atomic: bool) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+            Self::run_batch(bounty_ids, atomic, |bounty_id| {
+                Self::close_bounty_impl(who.clone(), bounty_id)
+            })
+        }
+
+        /// council accept or reject bounties in a batch
+        #[weight = T::WeightInfo::examine_bounty(Self::max_dependents()).saturating_mul(examinations.len() as Weight)]
+        fn batch_examine_bounties(origin, examinations: Vec<(BountyId, bool)>, atomic: bool) -> DispatchResult {
+            T::CouncilOrigin::ensure_origin(origin)?;
+            Self::run_batch(examinations, atomic, |(bounty_id, accepted)| {
+                Self::examine_bounty_impl(bounty_id, accepted)
+            })
+        }
+
+        /// reveal the committed remark, remarks apply once both sides revealed or the reveal period is over
+        #[weight = T::WeightInfo::reveal_remark().saturating_add(T::ReputationBuilder::behavior_hooks_weight().saturating_mul(4))]
+        fn reveal_remark(origin, bounty_id: BountyId, remark: BountyRemarkCollaborationResult, salt: [u8; 32]) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+            Self::reveal_remark_impl(bounty_id, who, remark, salt)
+        }
+
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use frame_support::{
+        impl_outer_origin, parameter_types,
+        storage::{StorageDoubleMap, StorageMap, StorageValue},
+        traits::OnRuntimeUpgrade,
+    };
+    use frame_system::EnsureRoot;
+    use sp_core::H256;
+    use sp_io::TestExternalities;
+    use sp_runtime::{
+        testing::Header,
+        traits::{BlakeTwo256, IdentityLookup},
+        Perbill,
+    };
+
+    use crate::types::{BountyMetaData, BountyMetaDataV1};
+
+    pub fn new_test_ext() -> TestExternalities {
+        let mut t = frame_system::GenesisConfig::default()
+            .build_storage::<Test>()
+            .unwrap();
+        orml_tokens::GenesisConfig::<Test> {
+            endowed_accounts: vec![(1, 0, 1000), (2, 0, 1000), (3, 0, 1000)],
+        }
+        .assimilate_storage(&mut t)
+        .unwrap();
+        TestExternalities::new(t)
+    }
+
+    impl_outer_origin! {
+        pub enum Origin for Test where system = frame_system {}
+    }
+
+    #[derive(Clone, Eq, PartialEq)]
+    pub struct Test;
+    parameter_types! {
+        pub const BlockHashCount: u64 = 250;
+        pub const MaximumBlockWeight: Weight = 1024;
+        pub const MaximumBlockLength: u32 = 2 * 1024;
+        pub const AvailableBlockRatio: Perbill = Perbill::one();
+        pub const ExistentialDeposit: u64 = 1;
+        pub const MaxLocks: u32 = 50;
+        pub const AppealDeposit: u64 = 10;
+        pub const CouncilAccount: u64 = 100;
+        pub const CouncilFee: Percent = Percent::from_percent(10);
+        pub const GetNativeCurrencyId: u32 = 0;
+        pub const ReapDeposit: u64 = 1;
+        pub const TipMining: bool = true;
+        pub const TipReputation: bool = true;
+    }
+    impl frame_system::Trait for Test {
+        type BaseCallFilter = ();
+        type Origin = Origin;
+        type Index = u64;
+        type BlockNumber = u64;
+        type Call = ();
+        type Hash = H256;
+        type Hashing = BlakeTwo256;
+        type AccountId = u64;
+        type Lookup = IdentityLookup<Self::AccountId>;
+        type Header = Header;
+        type Event = ();
+        type BlockHashCount = BlockHashCount;
+        type MaximumBlockWeight = MaximumBlockWeight;
+        type DbWeight = ();
+        type BlockExecutionWeight = ();
+        type ExtrinsicBaseWeight = ();
+        type MaximumExtrinsicWeight = MaximumBlockWeight;
+        type AvailableBlockRatio = AvailableBlockRatio;
+        type MaximumBlockLength = MaximumBlockLength;
+        type Version = ();
+        type PalletInfo = ();
+        type AccountData = pallet_balances::AccountData<u64>;
+        type OnNewAccount = ();
+        type OnKilledAccount = ();
+        type SystemWeightInfo = ();
+    }
+
+    impl pallet_balances::Trait for Test {
+        type MaxLocks = MaxLocks;
+        type Balance = u64;
+        type Event = ();
+        type DustRemoval = ();
+        type ExistentialDeposit = ExistentialDeposit;
+        type AccountStore = System;
+        type WeightInfo = ();
+    }
+
+    impl orml_tokens::Trait for Test {
+        type Event = ();
+        type Balance = u64;
+        type Amount = i64;
+        type CurrencyId = u32;
+        type OnReceived = ();
+        type WeightInfo = ();
+    }
+
+    impl ospallet_reputation::Trait for Test {
+        type Event = ();
+        type Currency = Balances;
+        type CouncilOrigin = EnsureRoot<u64>;
+        type AppealDeposit = AppealDeposit;
+        type WeighReputation = ();
+        type OnBehavior = ();
+        type WeightInfo = ();
+    }
+
+    impl ospallet_mining::Trait for Test {
+        type Event = ();
+        type Currency = Balances;
+        type WeightInfo = ();
+    }
+
+    impl Trait for Test {
+        type Event = ();
+        type Currency = Tokens;
+        type CouncilOrigin = EnsureRoot<u64>;
+        type CouncilAccount = CouncilAccount;
+        type CouncilFee = CouncilFee;
+        type DetermineBountyId = SequentialBountyIdDeterminer<Test>;
+        type BountyResolved = ();
+        type ReputationBuilder = Reputation;
+        type MiningPowerBuilder = Mining;
+        type GetNativeCurrencyId = GetNativeCurrencyId;
+        type ReapDeposit = ReapDeposit;
+        type TipMining = TipMining;
+        type TipReputation = TipReputation;
+        type WeightInfo = ();
+    }
+
+    type System = frame_system::Module<Test>;
+    type Balances = pallet_balances::Module<Test>;
+    type Tokens = orml_tokens::Module<Test>;
+    type Reputation = ospallet_reputation::Module<Test>;
+    type Mining = ospallet_mining::Module<Test>;
+    type OsBounties = Module<Test>;
+
+    fn create_bounty(funder: u64) -> BountyId {
+        let bounty = Bounty::V2(BountyMetaData {
+            owner: funder,
+            currency_id: 0,
+            payment: 100,
+            category: BountyCategory::Development,
+            title: b"bounty".to_vec(),
+            digest: Default::default(),
+        });
+        OsBounties::create_bounty_impl(funder, bounty).unwrap()
+    }
+
+    fn assigned_bounty(funder: u64, hunter: u64) -> BountyId {
+        let bounty_id = create_bounty(funder);
+        OsBounties::examine_bounty_impl(bounty_id, true).unwrap();
+        OsBounties::hunt_bounty_impl(bounty_id, hunter).unwrap();
+        OsBounties::assign_bounty_impl(bounty_id, funder, hunter).unwrap();
+        bounty_id
+    }
+
+    #[test]
+    fn state_index_works() {
+        new_test_ext().execute_with(|| {
+            let accepted = create_bounty(1);
+            let rejected = create_bounty(2);
+            assert_eq!(
+                OsBounties::bounties_in_state(BountyState::Applying, 0, 10).len(),
+                2
+            );
+
+            OsBounties::examine_bounty_impl(accepted, true).unwrap();
+            OsBounties::examine_bounty_impl(rejected, false).unwrap();
+            assert_eq!(
+                OsBounties::bounties_in_state(BountyState::Accepted, 0, 10),
+                vec![accepted]
+            );
+            assert_eq!(
+                OsBounties::bounties_in_state(BountyState::Rejected, 0, 10),
+                vec![rejected]
+            );
+            assert!(OsBounties::bounties_in_state(BountyState::Applying, 0, 10).is_empty());
+            assert_eq!(OsBounties::check_indices(), Ok(()));
+        });
+    }
+
+    #[test]
+    fn migrate_state_index_works() {
+        new_test_ext().execute_with(|| {
+            let accepted = create_bounty(1);
+            create_bounty(2);
+            OsBounties::examine_bounty_impl(accepted, true).unwrap();
+
+            // bounties created before the state index
+            BountiesByState::remove_prefix(BountyState::Applying);
+            BountiesByState::remove_prefix(BountyState::Accepted);
+            assert!(OsBounties::check_indices().is_err());
+
+            OsBounties::on_runtime_upgrade();
+            assert_eq!(OsBounties::storage_version(), Releases::V3_0_0);
+            assert_eq!(OsBounties::check_indices(), Ok(()));
+            assert_eq!(
+                OsBounties::bounties_in_state(BountyState::Accepted, 0, 10),
+                vec![accepted]
+            );
+        });
+    }
+
+    #[test]
+    fn migrate_bounty_layout_works() {
+        new_test_ext().execute_with(|| {
+            let bounty_id = create_bounty(1);
+            // a bounty created before categories
+            let legacy = Bounty::V1(BountyMetaDataV1 {
+                owner: 1,
+                currency_id: 0,
+                payment: 100,
+                title: b"legacy".to_vec(),
+                digest: Default::default(),
+            });
+            Bounties::<Test>::insert(bounty_id, legacy.clone());
+            BountiesByCategory::remove_prefix(BountyCategory::Development);
+            StorageVersion::put(Releases::V2_0_0);
+            assert!(OsBounties::check_indices().is_err());
+
+            OsBounties::on_runtime_upgrade();
+            assert_eq!(OsBounties::storage_version(), Releases::V3_0_0);
+            assert_eq!(OsBounties::bounties(bounty_id), Some(legacy.upgrade()));
+            assert_eq!(OsBounties::check_indices(), Ok(()));
+            assert_eq!(
+                OsBounties::bounties_in_category(BountyCategory::Development, 0, 10),
+                vec![bounty_id]
+            );
+        });
+    }
+
+    #[test]
+    fn migrate_backfills_deadline() {
+        new_test_ext().execute_with(|| {
+            System::set_block_number(5);
+            let assigned = assigned_bounty(1, 2);
+            let submitted = assigned_bounty(1, 3);
+            OsBounties::submit_bounty_impl(submitted, 3).unwrap();
+            let accepted = create_bounty(1);
+            OsBounties::examine_bounty_impl(accepted, true).unwrap();
+            // bounties assigned before deadlines
+            Deadline::<Test>::remove(assigned);
+            Deadline::<Test>::remove(submitted);
+            StorageVersion::put(Releases::V2_0_0);
+
+            OsBounties::on_runtime_upgrade();
+            let deadline = 5 + OsBounties::outdated_height();
+            assert_eq!(OsBounties::deadline(assigned), Some(deadline));
+            assert_eq!(OsBounties::deadline(submitted), Some(deadline));
+            assert_eq!(OsBounties::deadline(accepted), None);
+
+            System::set_block_number(deadline + 1);
+            assert!(OsBounties::is_outdated(assigned));
+        });
+    }
+
+    #[test]
+    fn scheduled_templates_are_carried_over() {
+        new_test_ext().execute_with(|| {
+            System::set_block_number(1);
+            MaxTemplatesPerBlock::put(1);
+            for index in 0..2 {
+                OsBounties::create_template_impl(
+                    1,
+                    0,
+                    10,
+                    BountyCategory::Design,
+                    b"task {}".to_vec(),
+                    Default::default(),
+                )
+                .unwrap();
+                OsBounties::schedule_template_impl(1, index, Some(5)).unwrap();
+            }
+
+            assert_eq!(OsBounties::spawn_scheduled_templates(6), (1, 1));
+            assert_eq!(OsBounties::spawn_scheduled_templates(7), (1, 0));
+            assert_eq!(OsBounties::spawned_count(0), 1);
+            assert_eq!(OsBounties::spawned_count(1), 1);
+            assert_eq!(
+                OsBounties::bounties_in_category(BountyCategory::Design, 0, 10).len(),
+                2
+            );
+        });
+    }
+
+    #[test]
+    fn long_title_pattern_is_rejected() {
+        new_test_ext().execute_with(|| {
+            MaxTitlePatternLength::put(4);
+            assert_eq!(
+                OsBounties::create_template_impl(
+                    1,
+                    0,
+                    10,
+                    BountyCategory::Design,
+                    b"task {}".to_vec(),
+                    Default::default(),
+                ),
+                Err(Error::<Test>::TitlePatternTooLong.into())
+            );
+        });
+    }
+
+    #[test]
+    fn owner_could_not_be_funder_or_hunter() {
+        new_test_ext().execute_with(|| {
+            let bounty_id = assigned_bounty(1, 2);
+            assert_eq!(
+                OsBounties::propose_bounty_owner_impl(bounty_id, 1, 1),
+                Err(Error::<Test>::AlreadyOwner.into())
+            );
+            assert_eq!(
+                OsBounties::propose_bounty_owner_impl(bounty_id, 1, 2),
+                Err(Error::<Test>::OwnerIsHunter.into())
+            );
+            OsBounties::propose_bounty_owner_impl(bounty_id, 1, 3).unwrap();
+            OsBounties::accept_bounty_ownership_impl(bounty_id, 3).unwrap();
+            assert_eq!(OsBounties::pending_owner(bounty_id), None);
+        });
+    }
+
+    #[test]
+    fn unassign_measures_inactivity_from_last_activity() {
+        new_test_ext().execute_with(|| {
+            InactivityThreshold::<Test>::put(100);
+            System::set_block_number(1);
+            let bounty_id = assigned_bounty(1, 2);
+
+            System::set_block_number(50);
+            OsBounties::request_extension_impl(bounty_id, 2, 10, Default::default()).unwrap();
+            assert_eq!(OsBounties::last_activity_height(bounty_id), 50);
+
+            // long after the assignment but not after the last action, the funder is penalized
+            System::set_block_number(120);
+            OsBounties::unassign_bounty_impl(bounty_id, 1).unwrap();
+            assert!(Reputation::funder_score(1) < 0);
+            assert_eq!(Reputation::hunter_score(2), 0);
+            assert!(!LastActivityHeight::<Test>::contains_key(bounty_id));
+        });
+    }
+
+    #[test]
+    fn overused_extensions_are_penalized_and_bounded() {
+        new_test_ext().execute_with(|| {
+            MaxExtensions::put(4);
+            let bounty_id = assigned_bounty(1, 2);
+            for _ in 0..OsBounties::max_free_extensions() {
+                OsBounties::request_extension_impl(bounty_id, 2, 10, Default::default()).unwrap();
+                OsBounties::approve_extension_impl(bounty_id, 1, true).unwrap();
+            }
+            assert_eq!(Reputation::hunter_score(2), 0);
+
+            OsBounties::request_extension_impl(bounty_id, 2, 10, Default::default()).unwrap();
+            OsBounties::approve_extension_impl(bounty_id, 1, true).unwrap();
+            assert_eq!(Reputation::hunter_score(2), Reputation::extension_penalty());
+
+            OsBounties::request_extension_impl(bounty_id, 2, 10, Default::default()).unwrap();
+            OsBounties::approve_extension_impl(bounty_id, 1, false).unwrap();
+            assert_eq!(
+                OsBounties::request_extension_impl(bounty_id, 2, 10, Default::default()),
+                Err(Error::<Test>::TooManyExtensions.into())
+            );
+        });
+    }
+
+    #[test]
+    fn force_close_outdated_bounty_works() {
+        new_test_ext().execute_with(|| {
+            System::set_block_number(1);
+            let bounty_id = assigned_bounty(1, 2);
+            OsBounties::request_extension_impl(bounty_id, 2, 10, Default::default()).unwrap();
+            OsBounties::approve_extension_impl(bounty_id, 1, true).unwrap();
+            let deadline = OsBounties::deadline(bounty_id).unwrap();
+            assert_eq!(deadline, 1 + OsBounties::outdated_height() + 10);
+
+            System::set_block_number(deadline);
+            assert_eq!(
+                OsBounties::force_close_bounty_impl(bounty_id, CloseReason::Outdated),
+                Err(Error::<Test>::NotOutdated.into())
+            );
+
+            System::set_block_number(deadline + 1);
+            OsBounties::force_close_bounty_impl(bounty_id, CloseReason::Outdated).unwrap();
+            assert_eq!(
+                OsBounties::bounty_state_of(bounty_id),
+                BountyState::Outdated
+            );
+            assert!(Reputation::hunter_score(2) < 0);
+            assert_eq!(
+                OsBounties::force_close_bounty_impl(bounty_id, CloseReason::Outdated),
+                Err(Error::<Test>::InvalidState.into())
+            );
+        });
+    }
+
+    #[test]
+    fn dependents_are_bounded() {
+        new_test_ext().execute_with(|| {
+            MaxDependents::put(1);
+            let prerequisite = create_bounty(1);
+            let dependent = create_bounty(2);
+            let other = create_bounty(2);
+            OsBounties::set_prerequisites_impl(dependent, 2, vec![prerequisite]).unwrap();
+            assert_eq!(
+                OsBounties::set_prerequisites_impl(other, 2, vec![prerequisite]),
+                Err(Error::<Test>::TooManyDependents.into())
+            );
+            // resetting the same prerequisites is not limited
+            OsBounties::set_prerequisites_impl(dependent, 2, vec![prerequisite]).unwrap();
+            assert_eq!(OsBounties::dependents(prerequisite), vec![dependent]);
+        });
+    }
+
+    #[test]
+    fn prerequisites_are_created_before() {
+        new_test_ext().execute_with(|| {
+            let first = create_bounty(1);
+            let second = create_bounty(1);
+            OsBounties::set_prerequisites_impl(second, 1, vec![first]).unwrap();
+            assert_eq!(
+                OsBounties::set_prerequisites_impl(first, 1, vec![second]),
+                Err(Error::<Test>::InvalidPrerequisites.into())
+            );
+            assert_eq!(
+                OsBounties::set_prerequisites_impl(first, 1, vec![first]),
+                Err(Error::<Test>::InvalidPrerequisites.into())
+            );
+        });
+    }
+
+    #[test]
+    fn failed_prerequisite_flags_unfinished_dependents() {
+        new_test_ext().execute_with(|| {
+            let prerequisite = create_bounty(1);
+            let dependent = create_bounty(2);
+            let closed = create_bounty(2);
+            OsBounties::set_prerequisites_impl(dependent, 2, vec![prerequisite]).unwrap();
+            OsBounties::set_prerequisites_impl(closed, 2, vec![prerequisite]).unwrap();
+            OsBounties::close_bounty_impl(2, closed).unwrap();
+
+            OsBounties::close_bounty_impl(1, prerequisite).unwrap();
+            assert_eq!(
+                OsBounties::broken_prerequisites(dependent),
+                vec![prerequisite]
+            );
+            assert!(OsBounties::broken_prerequisites(closed).is_empty());
+        });
+    }
+
+    #[test]
+    fn resolved_prerequisite_never_fails_dependents() {
+        new_test_ext().execute_with(|| {
+            let prerequisite = assigned_bounty(1, 2);
+            let dependent = create_bounty(3);
+            OsBounties::set_prerequisites_impl(dependent, 3, vec![prerequisite]).unwrap();
+            OsBounties::submit_bounty_impl(prerequisite, 2).unwrap();
+            OsBounties::resolve_bounty_and_remark_impl(prerequisite, 1, Default::default(), None)
+                .unwrap();
+
+            assert_eq!(
+                OsBounties::close_bounty_impl(1, prerequisite),
+                Err(Error::<Test>::InvalidState.into())
+            );
+            assert_eq!(
+                OsBounties::force_close_bounty_impl(prerequisite, CloseReason::InvalidState),
+                Err(Error::<Test>::InvalidState.into())
+            );
+            assert!(OsBounties::broken_prerequisites(dependent).is_empty());
+            assert_eq!(OsBounties::check_prerequisites(dependent), Ok(()));
+        });
+    }
+
+    #[test]
+    fn resolving_counts_per_category() {
+        new_test_ext().execute_with(|| {
+            for _ in 0..2 {
+                let bounty_id = assigned_bounty(1, 2);
+                OsBounties::submit_bounty_impl(bounty_id, 2).unwrap();
+                OsBounties::resolve_bounty_and_remark_impl(bounty_id, 1, Default::default(), None)
+                    .unwrap();
+            }
+
+            assert_eq!(
+                OsBounties::resolved_count(2, BountyCategory::Development),
+                2
+            );
+            assert_eq!(OsBounties::resolved_count(2, BountyCategory::Design), 0);
+            assert_eq!(
+                OsBounties::resolved_count(1, BountyCategory::Development),
+                0
+            );
+        });
+    }
+
+    #[test]
+    fn resolved_bounty_frees_hunter_slot() {
+        new_test_ext().execute_with(|| {
+            System::set_block_number(1);
+            MaxHoldingBounties::put(1);
+            let resolved = assigned_bounty(1, 2);
+            let held = create_bounty(1);
+            OsBounties::examine_bounty_impl(held, true).unwrap();
+            OsBounties::hunt_bounty_impl(held, 2).unwrap();
+            let next = create_bounty(3);
+            OsBounties::examine_bounty_impl(next, true).unwrap();
+            assert_eq!(
+                OsBounties::hunt_bounty_impl(next, 2),
+                Err(Error::<Test>::TooManyHuntedBounties.into())
+            );
+
+            OsBounties::submit_bounty_impl(resolved, 2).unwrap();
+            OsBounties::resolve_bounty_and_remark_impl(resolved, 1, Default::default(), None)
+                .unwrap();
+            let commit_end = OsBounties::remark_rounds(resolved).unwrap().commit_end;
+            System::set_block_number(commit_end);
+            assert_eq!(OsBounties::process_remark_agenda(commit_end), (1, 0));
+            assert!(!HunterBounties::<Test>::contains_key(2, resolved));
+            // kept for the archive
+            assert_eq!(OsBounties::hunted_for_bounty(resolved), 2);
+
+            OsBounties::hunt_bounty_impl(next, 2).unwrap();
+            assert_eq!(
+                OsBounties::remark_bounty_funder_impl(resolved, 2, Default::default()),
+                Err(Error::<Test>::RemarkWindowClosed.into())
+            );
+        });
+    }
+
+    #[test]
+    fn council_fee_is_charged_on_mining_tip() {
+        new_test_ext().execute_with(|| {
+            let bounty_id = assigned_bounty(1, 2);
+            OsBounties::submit_bounty_impl(bounty_id, 2).unwrap();
+            OsBounties::resolve_bounty_and_remark(
+                Origin::signed(1),
+                bounty_id,
+                Default::default(),
+                Some((0, 50)),
+            )
+            .unwrap();
+
+            // the reap deposit is still reserved
+            assert_eq!(Tokens::free_balance(0, &1), 1000 - 1 - 100 - 50);
+            assert_eq!(Tokens::free_balance(0, &2), 1000 + 90 + 45);
+            assert_eq!(Tokens::free_balance(0, &CouncilAccount::get()), 10 + 5);
+        });
+    }
+
+    #[test]
+    fn stream_is_settled_only_while_hunting() {
+        new_test_ext().execute_with(|| {
+            System::set_block_number(1);
+            let bounty_id = create_bounty(1);
+            OsBounties::set_streaming_impl(bounty_id, 1, true).unwrap();
+            OsBounties::examine_bounty_impl(bounty_id, true).unwrap();
+            OsBounties::hunt_bounty_impl(bounty_id, 2).unwrap();
+            OsBounties::assign_bounty_impl(bounty_id, 1, 2).unwrap();
+            OsBounties::submit_bounty_impl(bounty_id, 2).unwrap();
+
+            System::set_block_number(10);
+            let bounty = OsBounties::get_bounty(&bounty_id).unwrap();
+            assert_eq!(OsBounties::settle_stream(bounty_id, &bounty), Ok(()));
+            OsBounties::resolve_bounty_and_remark_impl(bounty_id, 1, Default::default(), None)
+                .unwrap();
+
+            System::set_block_number(20);
+            assert_eq!(
+                OsBounties::settle_stream(bounty_id, &bounty),
+                Err(Error::<Test>::InvalidState.into())
+            );
+            assert_eq!(
+                OsBounties::withdraw_vested_impl(bounty_id, 2),
+                Err(Error::<Test>::InvalidState.into())
+            );
+        });
+    }
+
+    #[test]
+    fn declined_hunters_are_bounded() {
+        new_test_ext().execute_with(|| {
+            ApplicantsLimit::put(1);
+            let bounty_id = create_bounty(1);
+            OsBounties::examine_bounty_impl(bounty_id, true).unwrap();
+            OsBounties::hunt_bounty_impl(bounty_id, 2).unwrap();
+            OsBounties::decline_hunter_impl(bounty_id, 1, 2, DeclineReason::Other).unwrap();
+
+            OsBounties::hunt_bounty_impl(bounty_id, 3).unwrap();
+            assert_eq!(
+                OsBounties::decline_hunter_impl(bounty_id, 1, 3, DeclineReason::Other),
+                Err(Error::<Test>::TooManyDeclined.into())
+            );
+            assert_eq!(OsBounties::declined_count(bounty_id), 1);
+        });
     }
 }
@@ -6,18 +6,12 @@ use serde::{Deserialize, Serialize};
 // Substrate
 use sp_runtime::RuntimeDebug;
 
+pub use opensquare_primitives::BountyCategory;
 use opensquare_primitives::SdDigest;
+use ospallet_reputation::BountyRemarkCollaborationResult;
 
 use crate::{BalanceOf, CurrencyIdOf};
 
-#[derive(PartialEq, Eq, Clone, Copy, Encode, Decode, RuntimeDebug)]
-#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
-pub enum BountyCategory {
-    Development,
-    Design,
-    Document,
-}
-
 #[derive(PartialEq, Eq, Clone, Copy, Encode, Decode, RuntimeDebug)]
 #[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
 pub enum BountyState {
@@ -42,10 +36,42 @@ impl Default for BountyState {
     }
 }
 
+impl BountyState {
+    /// A bounty in a terminal state will never transit to another state.
+    pub fn is_finished(&self) -> bool {
+        match self {
+            BountyState::Rejected
+            | BountyState::Closed
+            | BountyState::CouncilClosed
+            | BountyState::Outdated
+            | BountyState::Resolved => true,
+            _ => false,
+        }
+    }
+}
+
 #[derive(PartialEq, Eq, Clone, Encode, Decode, RuntimeDebug)]
 #[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
 pub enum Bounty<AccountId, CurrencyId, Balance> {
-    V1(BountyMetaData<AccountId, CurrencyId, Balance>),
+    V1(BountyMetaDataV1<AccountId, CurrencyId, Balance>),
+    V2(BountyMetaData<AccountId, CurrencyId, Balance>),
+}
+
+impl<AccountId, CurrencyId, Balance> Bounty<AccountId, CurrencyId, Balance> {
+    /// Bounty in the latest layout, bounties created before categories are development bounties.
+    pub fn upgrade(self) -> Self {
+        match self {
+            Bounty::V1(metadata) => Bounty::V2(BountyMetaData {
+                owner: metadata.owner,
+                currency_id: metadata.currency_id,
+                payment: metadata.payment,
+                category: BountyCategory::Development,
+                title: metadata.title,
+                digest: metadata.digest,
+            }),
+            bounty => bounty,
+        }
+    }
 }
 
 pub type BountyOf<T> = Bounty<<T as frame_system::Trait>::AccountId, CurrencyIdOf<T>, BalanceOf<T>>;
@@ -53,6 +79,18 @@ pub type BountyOf<T> = Bounty<<T as frame_system::Trait>::AccountId, CurrencyIdO
 #[derive(PartialEq, Eq, Clone, Encode, Decode, RuntimeDebug)]
 #[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
 pub struct BountyMetaData<AccountId, CurrencyId, Balance> {
+    pub owner: AccountId,
+    pub currency_id: CurrencyId,
+    pub payment: Balance,
+    pub category: BountyCategory,
+    pub title: Vec<u8>,
+    pub digest: SdDigest,
+}
+
+/// Layout of bounties created before categories, upgraded to `Bounty::V2` on runtime upgrade.
+#[derive(PartialEq, Eq, Clone, Encode, Decode, RuntimeDebug)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+pub struct BountyMetaDataV1<AccountId, CurrencyId, Balance> {
     pub owner: AccountId,
     pub currency_id: CurrencyId,
     pub payment: Balance,
@@ -60,6 +98,68 @@ pub struct BountyMetaData<AccountId, CurrencyId, Balance> {
     pub digest: SdDigest,
 }
 
+pub type TemplateIndex = u32;
+
+/// Template to create similar bounties repeatedly.
+#[derive(PartialEq, Eq, Clone, Encode, Decode, RuntimeDebug)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+pub struct BountyTemplate<AccountId, CurrencyId, Balance> {
+    pub owner: AccountId,
+    pub currency_id: CurrencyId,
+    pub payment: Balance,
+    pub category: BountyCategory,
+    /// title of spawned bounties, the first `{}` is replaced by the spawn sequence number
+    pub title_pattern: Vec<u8>,
+    pub digest: SdDigest,
+}
+
+pub type BountyTemplateOf<T> =
+    BountyTemplate<<T as frame_system::Trait>::AccountId, CurrencyIdOf<T>, BalanceOf<T>>;
+
+/// Schedule of a template to spawn bounties every `period` blocks.
+#[derive(PartialEq, Eq, Clone, Encode, Decode, RuntimeDebug)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+pub struct TemplateSchedule<BlockNumber> {
+    pub period: BlockNumber,
+    pub next_spawn: BlockNumber,
+}
+
+#[derive(PartialEq, Eq, Copy, Clone, Encode, Decode, RuntimeDebug)]
+pub enum Releases {
+    /// bounties are not indexed by state
+    V1_0_0,
+    /// `BountiesByState`
+    V2_0_0,
+    /// `Bounty::V2` with categories, `BountiesByCategory`
+    V3_0_0,
+}
+
+impl Default for Releases {
+    fn default() -> Self {
+        Releases::V1_0_0
+    }
+}
+
+/// Compact record kept for a reaped bounty, for reputation auditing.
+#[derive(PartialEq, Eq, Clone, Encode, Decode, RuntimeDebug)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+pub struct BountySummary<AccountId, CurrencyId, Balance, BlockNumber> {
+    pub funder: AccountId,
+    /// the assigned hunter, only exists for a resolved bounty
+    pub hunter: Option<AccountId>,
+    pub currency_id: CurrencyId,
+    pub payment: Balance,
+    pub outcome: BountyState,
+    pub finished_at: BlockNumber,
+}
+
+pub type BountySummaryOf<T> = BountySummary<
+    <T as frame_system::Trait>::AccountId,
+    CurrencyIdOf<T>,
+    BalanceOf<T>,
+    <T as frame_system::Trait>::BlockNumber,
+>;
+
 #[derive(PartialEq, Eq, Clone, Encode, Decode, RuntimeDebug)]
 #[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
 pub struct SettingData {
@@ -73,6 +173,40 @@ pub enum CloseReason {
     InvalidState,
 }
 
+/// Request from the assigned hunter for more time.
+#[derive(PartialEq, Eq, Clone, Encode, Decode, RuntimeDebug)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+pub struct ExtensionRequest<AccountId, BlockNumber> {
+    pub hunter: AccountId,
+    pub extra_blocks: BlockNumber,
+    pub reason_digest: SdDigest,
+    pub requested_at: BlockNumber,
+}
+
+pub type ExtensionRequestOf<T> = ExtensionRequest<
+    <T as frame_system::Trait>::AccountId,
+    <T as frame_system::Trait>::BlockNumber,
+>;
+
+/// Payment streamed to hunters of a streaming bounty.
+#[derive(PartialEq, Eq, Clone, Default, Encode, Decode, RuntimeDebug)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+pub struct StreamInfo<Balance> {
+    /// paid to previous assigned hunters
+    pub paid_before: Balance,
+    /// withdrawn by the current assigned hunter
+    pub withdrawn: Balance,
+}
+
+#[derive(PartialEq, Eq, Clone, Copy, Encode, Decode, RuntimeDebug)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+pub enum DeclineReason {
+    Unqualified,
+    Unresponsive,
+    Duplicated,
+    Other,
+}
+
 #[derive(PartialEq, Eq, Clone, Encode, Decode, RuntimeDebug)]
 #[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
 pub enum HunterBountyState {
@@ -85,3 +219,54 @@ impl Default for HunterBountyState {
         Self::Hunting
     }
 }
+
+/// Remark of one side in the remark phase of a resolved bounty.
+#[derive(PartialEq, Eq, Clone, Encode, Decode, RuntimeDebug)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+pub enum RemarkSlot<Hash> {
+    Empty,
+    Committed(Hash),
+    Revealed(BountyRemarkCollaborationResult),
+}
+
+impl<Hash> Default for RemarkSlot<Hash> {
+    fn default() -> Self {
+        RemarkSlot::Empty
+    }
+}
+
+impl<Hash> RemarkSlot<Hash> {
+    /// The revealed remark, or `default` if it's not revealed.
+    pub fn revealed_or(
+        &self,
+        default: BountyRemarkCollaborationResult,
+    ) -> BountyRemarkCollaborationResult {
+        match self {
+            RemarkSlot::Revealed(remark) => *remark,
+            _ => default,
+        }
+    }
+}
+
+/// Commit-reveal remark phase of a resolved bounty, remarks only apply after both sides revealed
+/// or the phase ended.
+#[derive(PartialEq, Eq, Clone, Encode, Decode, RuntimeDebug)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+pub struct RemarkRound<AccountId, Hash, BlockNumber> {
+    pub funder: AccountId,
+    pub hunter: AccountId,
+    /// remark of the funder on the hunter
+    pub funder_remark: RemarkSlot<Hash>,
+    /// remark of the hunter on the funder
+    pub hunter_remark: RemarkSlot<Hash>,
+    /// commitments are accepted before this height
+    pub commit_end: BlockNumber,
+    /// remarks not revealed before this height are replaced by `DefaultRemark`
+    pub reveal_end: BlockNumber,
+}
+
+pub type RemarkRoundOf<T> = RemarkRound<
+    <T as frame_system::Trait>::AccountId,
+    <T as frame_system::Trait>::Hash,
+    <T as frame_system::Trait>::BlockNumber,
+>;
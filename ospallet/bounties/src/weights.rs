@@ -0,0 +1,240 @@
+//! Weights for ospallet_bounties.
+//!
+//! `()` implements `WeightInfo` with the weights used in tests and chains without their own
+//! benchmark results. Runtimes should use the weights generated by the `benchmark` subcommand.
+//!
+//! Components:
+//! - `a`: hunters of a bounty, or hunters declined for a bounty, bounded by `ApplicantsLimit`
+//! - `h`: bounties held by a hunter, bounded by `MaxHoldingBounties`
+//! - `p`: prerequisites of a bounty, bounded by `MaxPrerequisites`, each with at most `MaxDependents`
+//!   dependents
+//! - `d`: dependents of a bounty, bounded by `MaxDependents`
+
+#![allow(unused_parens)]
+
+use frame_support::weights::{constants::RocksDbWeight as DbWeight, Weight};
+
+pub trait WeightInfo {
+    fn create_bounty() -> Weight;
+    fn close_bounty(a: u32, d: u32) -> Weight;
+    fn assign_bounty() -> Weight;
+    fn unassign_bounty() -> Weight;
+    fn approve_extension() -> Weight;
+    fn decline_hunter() -> Weight;
+    fn shortlist_hunter() -> Weight;
+    fn set_max_applicants() -> Weight;
+    fn set_streaming() -> Weight;
+    fn set_prerequisites(p: u32) -> Weight;
+    fn create_template() -> Weight;
+    fn remove_template() -> Weight;
+    fn instantiate_template() -> Weight;
+    fn schedule_template() -> Weight;
+    fn propose_bounty_owner() -> Weight;
+    fn accept_bounty_ownership() -> Weight;
+    fn resolve_bounty_and_remark() -> Weight;
+    fn examine_bounty(d: u32) -> Weight;
+    fn force_close_bounty(a: u32, d: u32) -> Weight;
+    fn hunt_bounty(h: u32, a: u32) -> Weight;
+    fn submit_bounty() -> Weight;
+    fn cancel_hunt_bounty() -> Weight;
+    fn resign_from_bounty() -> Weight;
+    fn withdraw_vested() -> Weight;
+    fn request_extension() -> Weight;
+    fn remark_bounty_funder(a: u32) -> Weight;
+    fn reveal_remark() -> Weight;
+    fn reap_bounty(p: u32, a: u32) -> Weight;
+    fn spawn_template() -> Weight;
+    fn close_remark_window(a: u32) -> Weight;
+    fn finalize_remark_round() -> Weight;
+}
+
+impl WeightInfo for () {
+    fn create_bounty() -> Weight {
+        (95_000_000 as Weight)
+            .saturating_add(DbWeight::get().reads(8 as Weight))
+            .saturating_add(DbWeight::get().writes(12 as Weight))
+    }
+    fn close_bounty(a: u32, d: u32) -> Weight {
+        (80_000_000 as Weight)
+            .saturating_add((9_000_000 as Weight).saturating_mul(a as Weight))
+            .saturating_add((6_000_000 as Weight).saturating_mul(d as Weight))
+            .saturating_add(DbWeight::get().reads(10 as Weight))
+            .saturating_add(DbWeight::get().reads((1 as Weight).saturating_mul(a as Weight)))
+            .saturating_add(DbWeight::get().reads((3 as Weight).saturating_mul(d as Weight)))
+            .saturating_add(DbWeight::get().writes(8 as Weight))
+            .saturating_add(DbWeight::get().writes((2 as Weight).saturating_mul(a as Weight)))
+            .saturating_add(DbWeight::get().writes((1 as Weight).saturating_mul(d as Weight)))
+    }
+    fn assign_bounty() -> Weight {
+        (75_000_000 as Weight)
+            .saturating_add(DbWeight::get().reads(9 as Weight))
+            .saturating_add(DbWeight::get().writes(9 as Weight))
+    }
+    fn unassign_bounty() -> Weight {
+        (85_000_000 as Weight)
+            .saturating_add(DbWeight::get().reads(10 as Weight))
+            .saturating_add(DbWeight::get().writes(10 as Weight))
+    }
+    fn approve_extension() -> Weight {
+        (55_000_000 as Weight)
+            .saturating_add(DbWeight::get().reads(7 as Weight))
+            .saturating_add(DbWeight::get().writes(4 as Weight))
+    }
+    fn decline_hunter() -> Weight {
+        (45_000_000 as Weight)
+            .saturating_add(DbWeight::get().reads(4 as Weight))
+            .saturating_add(DbWeight::get().writes(5 as Weight))
+    }
+    fn shortlist_hunter() -> Weight {
+        (35_000_000 as Weight)
+            .saturating_add(DbWeight::get().reads(2 as Weight))
+            .saturating_add(DbWeight::get().writes(1 as Weight))
+    }
+    fn set_max_applicants() -> Weight {
+        (30_000_000 as Weight)
+            .saturating_add(DbWeight::get().reads(2 as Weight))
+            .saturating_add(DbWeight::get().writes(1 as Weight))
+    }
+    fn set_streaming() -> Weight {
+        (30_000_000 as Weight)
+            .saturating_add(DbWeight::get().reads(2 as Weight))
+            .saturating_add(DbWeight::get().writes(1 as Weight))
+    }
+    fn set_prerequisites(p: u32) -> Weight {
+        (40_000_000 as Weight)
+            .saturating_add((12_000_000 as Weight).saturating_mul(p as Weight))
+            .saturating_add(DbWeight::get().reads(4 as Weight))
+            .saturating_add(DbWeight::get().reads((5 as Weight).saturating_mul(p as Weight)))
+            .saturating_add(DbWeight::get().writes(3 as Weight))
+            .saturating_add(DbWeight::get().writes((2 as Weight).saturating_mul(p as Weight)))
+    }
+    fn create_template() -> Weight {
+        (35_000_000 as Weight)
+            .saturating_add(DbWeight::get().reads(1 as Weight))
+            .saturating_add(DbWeight::get().writes(2 as Weight))
+    }
+    fn remove_template() -> Weight {
+        (35_000_000 as Weight)
+            .saturating_add(DbWeight::get().reads(1 as Weight))
+            .saturating_add(DbWeight::get().writes(3 as Weight))
+    }
+    fn instantiate_template() -> Weight {
+        (110_000_000 as Weight)
+            .saturating_add(DbWeight::get().reads(10 as Weight))
+            .saturating_add(DbWeight::get().writes(13 as Weight))
+    }
+    fn schedule_template() -> Weight {
+        (35_000_000 as Weight)
+            .saturating_add(DbWeight::get().reads(1 as Weight))
+            .saturating_add(DbWeight::get().writes(2 as Weight))
+    }
+    fn propose_bounty_owner() -> Weight {
+        (30_000_000 as Weight)
+            .saturating_add(DbWeight::get().reads(3 as Weight))
+            .saturating_add(DbWeight::get().writes(1 as Weight))
+    }
+    fn accept_bounty_ownership() -> Weight {
+        (90_000_000 as Weight)
+            .saturating_add(DbWeight::get().reads(12 as Weight))
+            .saturating_add(DbWeight::get().writes(9 as Weight))
+    }
+    fn resolve_bounty_and_remark() -> Weight {
+        (125_000_000 as Weight)
+            .saturating_add(DbWeight::get().reads(14 as Weight))
+            .saturating_add(DbWeight::get().writes(14 as Weight))
+    }
+    fn examine_bounty(d: u32) -> Weight {
+        (50_000_000 as Weight)
+            .saturating_add((6_000_000 as Weight).saturating_mul(d as Weight))
+            .saturating_add(DbWeight::get().reads(5 as Weight))
+            .saturating_add(DbWeight::get().reads((3 as Weight).saturating_mul(d as Weight)))
+            .saturating_add(DbWeight::get().writes(5 as Weight))
+            .saturating_add(DbWeight::get().writes((1 as Weight).saturating_mul(d as Weight)))
+    }
+    fn force_close_bounty(a: u32, d: u32) -> Weight {
+        (100_000_000 as Weight)
+            .saturating_add((9_000_000 as Weight).saturating_mul(a as Weight))
+            .saturating_add((6_000_000 as Weight).saturating_mul(d as Weight))
+            .saturating_add(DbWeight::get().reads(15 as Weight))
+            .saturating_add(DbWeight::get().reads((1 as Weight).saturating_mul(a as Weight)))
+            .saturating_add(DbWeight::get().reads((3 as Weight).saturating_mul(d as Weight)))
+            .saturating_add(DbWeight::get().writes(12 as Weight))
+            .saturating_add(DbWeight::get().writes((2 as Weight).saturating_mul(a as Weight)))
+            .saturating_add(DbWeight::get().writes((1 as Weight).saturating_mul(d as Weight)))
+    }
+    fn hunt_bounty(h: u32, a: u32) -> Weight {
+        (50_000_000 as Weight)
+            .saturating_add((2_000_000 as Weight).saturating_mul(h as Weight))
+            .saturating_add((2_000_000 as Weight).saturating_mul(a as Weight))
+            .saturating_add(DbWeight::get().reads(6 as Weight))
+            .saturating_add(DbWeight::get().reads((1 as Weight).saturating_mul(h as Weight)))
+            .saturating_add(DbWeight::get().reads((1 as Weight).saturating_mul(a as Weight)))
+            .saturating_add(DbWeight::get().writes(2 as Weight))
+    }
+    fn submit_bounty() -> Weight {
+        (40_000_000 as Weight)
+            .saturating_add(DbWeight::get().reads(2 as Weight))
+            .saturating_add(DbWeight::get().writes(3 as Weight))
+    }
+    fn cancel_hunt_bounty() -> Weight {
+        (40_000_000 as Weight)
+            .saturating_add(DbWeight::get().reads(1 as Weight))
+            .saturating_add(DbWeight::get().writes(3 as Weight))
+    }
+    fn resign_from_bounty() -> Weight {
+        (80_000_000 as Weight)
+            .saturating_add(DbWeight::get().reads(9 as Weight))
+            .saturating_add(DbWeight::get().writes(10 as Weight))
+    }
+    fn withdraw_vested() -> Weight {
+        (75_000_000 as Weight)
+            .saturating_add(DbWeight::get().reads(9 as Weight))
+            .saturating_add(DbWeight::get().writes(4 as Weight))
+    }
+    fn request_extension() -> Weight {
+        (40_000_000 as Weight)
+            .saturating_add(DbWeight::get().reads(4 as Weight))
+            .saturating_add(DbWeight::get().writes(2 as Weight))
+    }
+    fn remark_bounty_funder(a: u32) -> Weight {
+        (40_000_000 as Weight)
+            .saturating_add((9_000_000 as Weight).saturating_mul(a as Weight))
+            .saturating_add(DbWeight::get().reads(2 as Weight))
+            .saturating_add(DbWeight::get().reads((1 as Weight).saturating_mul(a as Weight)))
+            .saturating_add(DbWeight::get().writes(3 as Weight))
+            .saturating_add(DbWeight::get().writes((2 as Weight).saturating_mul(a as Weight)))
+    }
+    fn reveal_remark() -> Weight {
+        (40_000_000 as Weight)
+            .saturating_add(DbWeight::get().reads(1 as Weight))
+            .saturating_add(DbWeight::get().writes(1 as Weight))
+    }
+    fn reap_bounty(p: u32, a: u32) -> Weight {
+        (120_000_000 as Weight)
+            .saturating_add((10_000_000 as Weight).saturating_mul(p as Weight))
+            .saturating_add((1_500_000 as Weight).saturating_mul(a as Weight))
+            .saturating_add(DbWeight::get().reads(10 as Weight))
+            .saturating_add(DbWeight::get().reads((1 as Weight).saturating_mul(p as Weight)))
+            .saturating_add(DbWeight::get().writes(26 as Weight))
+            .saturating_add(DbWeight::get().writes((1 as Weight).saturating_mul(p as Weight)))
+            .saturating_add(DbWeight::get().writes((1 as Weight).saturating_mul(a as Weight)))
+    }
+    fn spawn_template() -> Weight {
+        (110_000_000 as Weight)
+            .saturating_add(DbWeight::get().reads(12 as Weight))
+            .saturating_add(DbWeight::get().writes(15 as Weight))
+    }
+    fn close_remark_window(a: u32) -> Weight {
+        (30_000_000 as Weight)
+            .saturating_add((9_000_000 as Weight).saturating_mul(a as Weight))
+            .saturating_add(DbWeight::get().reads(1 as Weight))
+            .saturating_add(DbWeight::get().reads((1 as Weight).saturating_mul(a as Weight)))
+            .saturating_add(DbWeight::get().writes(2 as Weight))
+            .saturating_add(DbWeight::get().writes((2 as Weight).saturating_mul(a as Weight)))
+    }
+    fn finalize_remark_round() -> Weight {
+        (140_000_000 as Weight)
+            .saturating_add(DbWeight::get().reads(20 as Weight))
+            .saturating_add(DbWeight::get().writes(22 as Weight))
+    }
+}
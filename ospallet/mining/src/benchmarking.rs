@@ -0,0 +1,30 @@
+//! Benchmarks for ospallet_mining.
+
+#![cfg(feature = "runtime-benchmarks")]
+
+use frame_benchmarking::{benchmarks, whitelisted_caller};
+use frame_support::storage::{StorageDoubleMap, StorageMap};
+use frame_system::RawOrigin;
+use sp_runtime::traits::SaturatedConversion;
+
+use crate::constants::DEFAULT_BLOCKS_PER_SESSION;
+use crate::{
+    BalanceOf, Module, SessionAccountMiningPower, SessionTotalMiningPower, SessionTotalReward,
+    Trait,
+};
+
+benchmarks! {
+    _ { }
+
+    claim {
+        let caller: T::AccountId = whitelisted_caller();
+        let reward: BalanceOf<T> = 1_000_000_000_000u128.saturated_into();
+        SessionAccountMiningPower::<T>::insert(0, &caller, 10_000);
+        SessionTotalMiningPower::insert(0, 10_000);
+        SessionTotalReward::<T>::insert(0, reward);
+        frame_system::Module::<T>::set_block_number(DEFAULT_BLOCKS_PER_SESSION.into());
+    }: _(RawOrigin::Signed(caller.clone()), 0)
+    verify {
+        assert_eq!(Module::<T>::session_account_mining_power(0, &caller), 0);
+    }
+}
@@ -15,13 +15,21 @@ use crate::constants::DEFAULT_BLOCKS_PER_SESSION;
 pub use crate::types::{MiningPower, MiningPowerBuilder, SessionIndex};
 pub use opensquare_primitives::CurrencyId;
 
+#[cfg(feature = "runtime-benchmarks")]
+mod benchmarking;
 mod constants;
 mod types;
+mod weights;
+
+pub use weights::WeightInfo;
 
 pub trait Trait: system::Trait {
     type Event: From<Event<Self>> + Into<<Self as frame_system::Trait>::Event>;
 
     type Currency: Currency<Self::AccountId>;
+
+    /// Weight information for extrinsics in this pallet.
+    type WeightInfo: WeightInfo;
 }
 
 pub type BalanceOf<T> =
@@ -66,7 +74,7 @@ decl_module! {
 
         fn deposit_event() = default;
 
-        #[weight = 0]
+        #[weight = T::WeightInfo::claim()]
         fn claim(origin, session_index: SessionIndex) -> DispatchResult {
             let who = ensure_signed(origin)?;
 
@@ -0,0 +1,20 @@
+//! Weights for ospallet_mining.
+//!
+//! `()` implements `WeightInfo` with the weights used in tests and chains without their own
+//! benchmark results. Runtimes should use the weights generated by the `benchmark` subcommand.
+
+#![allow(unused_parens)]
+
+use frame_support::weights::{constants::RocksDbWeight as DbWeight, Weight};
+
+pub trait WeightInfo {
+    fn claim() -> Weight;
+}
+
+impl WeightInfo for () {
+    fn claim() -> Weight {
+        (70_000_000 as Weight)
+            .saturating_add(DbWeight::get().reads(5 as Weight))
+            .saturating_add(DbWeight::get().writes(3 as Weight))
+    }
+}
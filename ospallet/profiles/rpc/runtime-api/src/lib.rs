@@ -0,0 +1,22 @@
+//! Runtime API definition for the profiles module.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+#![allow(clippy::too_many_arguments, clippy::unnecessary_mut_passed)]
+
+use codec::Codec;
+use sp_std::prelude::*;
+
+use ospallet_bounties::BountyCategory;
+use ospallet_profiles::Profile;
+
+sp_api::decl_runtime_apis! {
+    pub trait ProfilesApi<AccountId> where
+        AccountId: Codec,
+    {
+        /// Get the profile of an account, with whether each skill is verified.
+        fn profile_of(who: AccountId) -> Option<Profile>;
+
+        /// Get accounts claiming the skill, paginated by `offset` and `limit`.
+        fn accounts_with_skill(category: BountyCategory, verified_only: bool, offset: u32, limit: u32) -> Vec<AccountId>;
+    }
+}
@@ -0,0 +1,49 @@
+//! Benchmarks for ospallet_profiles.
+
+#![cfg(feature = "runtime-benchmarks")]
+
+use frame_benchmarking::{benchmarks, whitelisted_caller};
+use frame_system::RawOrigin;
+use sp_std::{prelude::*, vec};
+
+use ospallet_bounties::BountyCategory;
+
+use crate::{Module, Trait};
+
+const ALL_SKILLS: [BountyCategory; 3] = [
+    BountyCategory::Development,
+    BountyCategory::Design,
+    BountyCategory::Document,
+];
+
+benchmarks! {
+    _ { }
+
+    set_profile {
+        let s in 0 .. ALL_SKILLS.len() as u32;
+        let caller: T::AccountId = whitelisted_caller();
+        // replacing a profile costs more than creating one
+        Module::<T>::set_profile_impl(caller.clone(), vec![b'x'; 64], Default::default(), ALL_SKILLS.to_vec())?;
+        let skills = ALL_SKILLS[..s as usize].to_vec();
+    }: _(RawOrigin::Signed(caller.clone()), vec![b'y'; 64], Default::default(), skills)
+    verify {
+        assert_eq!(Module::<T>::profiles(&caller).map(|p| p.skills.len() as u32), Some(s));
+    }
+
+    clear_profile {
+        let caller: T::AccountId = whitelisted_caller();
+        Module::<T>::set_profile_impl(caller.clone(), vec![b'x'; 64], Default::default(), ALL_SKILLS.to_vec())?;
+    }: _(RawOrigin::Signed(caller.clone()))
+    verify {
+        assert!(Module::<T>::profiles(&caller).is_none());
+    }
+
+    // worst case: the resolved bounty verifies a claimed skill
+    note_resolved {
+        let caller: T::AccountId = whitelisted_caller();
+        Module::<T>::set_profile_impl(caller.clone(), vec![b'x'; 64], Default::default(), ALL_SKILLS.to_vec())?;
+    }: { Module::<T>::note_resolved(&caller, BountyCategory::Development) }
+    verify {
+        assert!(Module::<T>::accounts_by_skill(BountyCategory::Development, &caller));
+    }
+}
@@ -0,0 +1,389 @@
+#![cfg_attr(not(feature = "std"), no_std)]
+
+use frame_support::{
+    decl_error, decl_event, decl_module, decl_storage, dispatch::DispatchResult, ensure,
+    storage::IterableStorageDoubleMap, weights::Weight,
+};
+use frame_system::ensure_signed;
+use sp_std::prelude::*;
+
+use opensquare_primitives::SdDigest;
+use ospallet_bounties::{BountyCategory, BountyOf, BountyResolved};
+
+pub use crate::types::{Profile, SkillTag};
+
+#[cfg(feature = "runtime-benchmarks")]
+mod benchmarking;
+mod types;
+mod weights;
+
+pub use weights::WeightInfo;
+
+pub trait Trait: ospallet_bounties::Trait {
+    type Event: From<Event<Self>> + Into<<Self as frame_system::Trait>::Event>;
+
+    /// Weight information for extrinsics in this pallet.
+    type WeightInfo: WeightInfo;
+}
+
+decl_error! {
+    pub enum Error for Module<T: Trait> {
+        /// display name is longer than `MaxDisplayNameLength`
+        NameTooLong,
+        /// a skill is claimed more than once
+        DuplicatedSkill,
+        /// this account has no profile
+        NoProfile,
+    }
+}
+
+decl_event!(
+    pub enum Event<T> where
+        <T as frame_system::Trait>::AccountId,
+    {
+        ProfileSet(AccountId),
+        ProfileCleared(AccountId),
+        SkillVerified(AccountId, BountyCategory),
+    }
+);
+
+decl_storage! {
+    trait Store for Module<T: Trait> as OsProfiles {
+        pub Profiles get(fn profiles): map hasher(blake2_128_concat) T::AccountId => Option<Profile>;
+        /// Accounts claiming a skill, with whether the skill is verified
+        pub AccountsBySkill get(fn accounts_by_skill):
+            double_map hasher(twox_64_concat) BountyCategory, hasher(blake2_128_concat) T::AccountId => bool;
+
+        pub MaxDisplayNameLength get(fn max_display_name_length): u32 = 64;
+    }
+}
+
+decl_module! {
+    pub struct Module<T: Trait> for enum Call where origin: T::Origin {
+        type Error = Error<T>;
+
+        fn deposit_event() = default;
+
+        /// register or update the profile, a skill is verified if the account has resolved bounties of it
+        #[weight = <T as Trait>::WeightInfo::set_profile(skills.len() as u32)]
+        fn set_profile(origin, display_name: Vec<u8>, digest: SdDigest, skills: Vec<BountyCategory>) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+            Self::set_profile_impl(who, display_name, digest, skills)
+        }
+
+        #[weight = <T as Trait>::WeightInfo::clear_profile()]
+        fn clear_profile(origin) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+            let profile = Self::profiles(&who).ok_or(Error::<T>::NoProfile)?;
+
+            for tag in profile.skills.iter() {
+                AccountsBySkill::<T>::remove(tag.category, &who);
+            }
+            Profiles::<T>::remove(&who);
+
+            Self::deposit_event(RawEvent::ProfileCleared(who));
+            Ok(())
+        }
+    }
+}
+
+impl<T: Trait> Module<T> {
+    fn set_profile_impl(
+        who: T::AccountId,
+        display_name: Vec<u8>,
+        digest: SdDigest,
+        skills: Vec<BountyCategory>,
+    ) -> DispatchResult {
+        ensure!(
+            display_name.len() as u32 <= Self::max_display_name_length(),
+            Error::<T>::NameTooLong
+        );
+        for (i, category) in skills.iter().enumerate() {
+            ensure!(!skills[..i].contains(category), Error::<T>::DuplicatedSkill);
+        }
+
+        if let Some(old) = Self::profiles(&who) {
+            for tag in old.skills.iter() {
+                AccountsBySkill::<T>::remove(tag.category, &who);
+            }
+        }
+        let skills = skills
+            .into_iter()
+            .map(|category| {
+                let verified = ospallet_bounties::Module::<T>::resolved_count(&who, category) > 0;
+                AccountsBySkill::<T>::insert(category, &who, verified);
+                SkillTag { category, verified }
+            })
+            .collect();
+        Profiles::<T>::insert(
+            &who,
+            Profile {
+                display_name,
+                digest,
+                skills,
+            },
+        );
+
+        Self::deposit_event(RawEvent::ProfileSet(who));
+        Ok(())
+    }
+
+    /// Verify the skill tag of the hunter related to a resolved bounty.
+    fn note_resolved(hunter: &T::AccountId, category: BountyCategory) {
+        Profiles::<T>::mutate(hunter, |profile| {
+            if let Some(profile) = profile {
+                for tag in profile.skills.iter_mut() {
+                    if tag.category == category && !tag.verified {
+                        tag.verified = true;
+                        AccountsBySkill::<T>::insert(category, hunter, true);
+                        Self::deposit_event(RawEvent::SkillVerified(hunter.clone(), category));
+                    }
+                }
+            }
+        });
+    }
+
+    /// Get accounts claiming the skill, skip `offset` items and return at most `limit` items.
+    pub fn accounts_with_skill(
+        category: BountyCategory,
+        verified_only: bool,
+        offset: u32,
+        limit: u32,
+    ) -> Vec<T::AccountId> {
+        AccountsBySkill::<T>::iter_prefix(category)
+            .filter(|(_, verified)| *verified || !verified_only)
+            .map(|(who, _)| who)
+            .skip(offset as usize)
+            .take(limit as usize)
+            .collect()
+    }
+}
+
+impl<T: Trait> BountyResolved<T> for Module<T> {
+    fn after_bounty_resolved(bounty: &BountyOf<T>, hunter: &T::AccountId) {
+        Self::note_resolved(hunter, ospallet_bounties::Module::<T>::get_category(bounty));
+    }
+
+    fn after_bounty_resolved_weight() -> Weight {
+        <T as Trait>::WeightInfo::note_resolved()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use frame_support::{impl_outer_origin, parameter_types, weights::Weight};
+    use frame_system::EnsureRoot;
+    use sp_core::H256;
+    use sp_io::TestExternalities;
+    use sp_runtime::{
+        testing::Header,
+        traits::{BlakeTwo256, IdentityLookup},
+        Perbill, Percent,
+    };
+
+    pub fn new_test_ext() -> TestExternalities {
+        let t = frame_system::GenesisConfig::default()
+            .build_storage::<Test>()
+            .unwrap();
+        TestExternalities::new(t)
+    }
+
+    impl_outer_origin! {
+        pub enum Origin for Test where system = frame_system {}
+    }
+
+    #[derive(Clone, Eq, PartialEq)]
+    pub struct Test;
+    parameter_types! {
+        pub const BlockHashCount: u64 = 250;
+        pub const MaximumBlockWeight: Weight = 1024;
+        pub const MaximumBlockLength: u32 = 2 * 1024;
+        pub const AvailableBlockRatio: Perbill = Perbill::one();
+        pub const ExistentialDeposit: u64 = 1;
+        pub const MaxLocks: u32 = 50;
+        pub const AppealDeposit: u64 = 10;
+        pub const CouncilAccount: u64 = 100;
+        pub const CouncilFee: Percent = Percent::from_percent(10);
+        pub const GetNativeCurrencyId: u32 = 0;
+        pub const ReapDeposit: u64 = 1;
+        pub const TipMining: bool = true;
+        pub const TipReputation: bool = true;
+    }
+    impl frame_system::Trait for Test {
+        type BaseCallFilter = ();
+        type Origin = Origin;
+        type Index = u64;
+        type BlockNumber = u64;
+        type Call = ();
+        type Hash = H256;
+        type Hashing = BlakeTwo256;
+        type AccountId = u64;
+        type Lookup = IdentityLookup<Self::AccountId>;
+        type Header = Header;
+        type Event = ();
+        type BlockHashCount = BlockHashCount;
+        type MaximumBlockWeight = MaximumBlockWeight;
+        type DbWeight = ();
+        type BlockExecutionWeight = ();
+        type ExtrinsicBaseWeight = ();
+        type MaximumExtrinsicWeight = MaximumBlockWeight;
+        type AvailableBlockRatio = AvailableBlockRatio;
+        type MaximumBlockLength = MaximumBlockLength;
+        type Version = ();
+        type PalletInfo = ();
+        type AccountData = pallet_balances::AccountData<u64>;
+        type OnNewAccount = ();
+        type OnKilledAccount = ();
+        type SystemWeightInfo = ();
+    }
+
+    impl pallet_balances::Trait for Test {
+        type MaxLocks = MaxLocks;
+        type Balance = u64;
+        type Event = ();
+        type DustRemoval = ();
+        type ExistentialDeposit = ExistentialDeposit;
+        type AccountStore = System;
+        type WeightInfo = ();
+    }
+
+    impl orml_tokens::Trait for Test {
+        type Event = ();
+        type Balance = u64;
+        type Amount = i64;
+        type CurrencyId = u32;
+        type OnReceived = ();
+        type WeightInfo = ();
+    }
+
+    impl ospallet_reputation::Trait for Test {
+        type Event = ();
+        type Currency = Balances;
+        type CouncilOrigin = EnsureRoot<u64>;
+        type AppealDeposit = AppealDeposit;
+        type WeighReputation = ();
+        type OnBehavior = ();
+        type WeightInfo = ();
+    }
+
+    impl ospallet_mining::Trait for Test {
+        type Event = ();
+        type Currency = Balances;
+        type WeightInfo = ();
+    }
+
+    impl ospallet_bounties::Trait for Test {
+        type Event = ();
+        type Currency = Tokens;
+        type CouncilOrigin = EnsureRoot<u64>;
+        type CouncilAccount = CouncilAccount;
+        type CouncilFee = CouncilFee;
+        type DetermineBountyId = ospallet_bounties::SequentialBountyIdDeterminer<Test>;
+        type BountyResolved = OsProfiles;
+        type ReputationBuilder = Reputation;
+        type MiningPowerBuilder = Mining;
+        type GetNativeCurrencyId = GetNativeCurrencyId;
+        type ReapDeposit = ReapDeposit;
+        type TipMining = TipMining;
+        type TipReputation = TipReputation;
+        type WeightInfo = ();
+    }
+
+    impl Trait for Test {
+        type Event = ();
+        type WeightInfo = ();
+    }
+
+    type System = frame_system::Module<Test>;
+    type Balances = pallet_balances::Module<Test>;
+    type Tokens = orml_tokens::Module<Test>;
+    type Reputation = ospallet_reputation::Module<Test>;
+    type Mining = ospallet_mining::Module<Test>;
+    type OsProfiles = Module<Test>;
+
+    fn skills_of(who: u64) -> Vec<SkillTag> {
+        OsProfiles::profiles(who).unwrap().skills
+    }
+
+    /// Count a resolved bounty like `ospallet_bounties` does before calling the handlers.
+    fn resolve(hunter: u64, category: BountyCategory) {
+        ospallet_bounties::ResolvedCount::<Test>::mutate(hunter, category, |count| *count += 1);
+        OsProfiles::note_resolved(&hunter, category);
+    }
+
+    #[test]
+    fn resolved_bounties_verify_claimed_skills() {
+        new_test_ext().execute_with(|| {
+            OsProfiles::set_profile_impl(
+                1,
+                b"alice".to_vec(),
+                Default::default(),
+                vec![BountyCategory::Design, BountyCategory::Development],
+            )
+            .unwrap();
+            assert!(skills_of(1).iter().all(|tag| !tag.verified));
+            assert_eq!(
+                OsProfiles::accounts_with_skill(BountyCategory::Design, false, 0, 10),
+                vec![1]
+            );
+            assert!(
+                OsProfiles::accounts_with_skill(BountyCategory::Design, true, 0, 10).is_empty()
+            );
+
+            // only the skill of the resolved category is verified
+            resolve(1, BountyCategory::Design);
+            assert_eq!(
+                skills_of(1),
+                vec![
+                    SkillTag {
+                        category: BountyCategory::Design,
+                        verified: true,
+                    },
+                    SkillTag {
+                        category: BountyCategory::Development,
+                        verified: false,
+                    },
+                ]
+            );
+            assert_eq!(
+                OsProfiles::accounts_with_skill(BountyCategory::Design, true, 0, 10),
+                vec![1]
+            );
+            assert!(
+                OsProfiles::accounts_with_skill(BountyCategory::Development, true, 0, 10)
+                    .is_empty()
+            );
+        });
+    }
+
+    #[test]
+    fn skills_claimed_after_resolving_are_verified() {
+        new_test_ext().execute_with(|| {
+            // bounties resolved without a profile still count
+            resolve(1, BountyCategory::Document);
+            OsProfiles::set_profile_impl(
+                1,
+                b"alice".to_vec(),
+                Default::default(),
+                vec![BountyCategory::Document, BountyCategory::Design],
+            )
+            .unwrap();
+            assert_eq!(
+                skills_of(1),
+                vec![
+                    SkillTag {
+                        category: BountyCategory::Document,
+                        verified: true,
+                    },
+                    SkillTag {
+                        category: BountyCategory::Design,
+                        verified: false,
+                    },
+                ]
+            );
+            assert!(OsProfiles::accounts_by_skill(BountyCategory::Document, 1));
+        });
+    }
+}
@@ -0,0 +1,26 @@
+use codec::{Decode, Encode};
+#[cfg(feature = "std")]
+use serde::{Deserialize, Serialize};
+use sp_std::prelude::*;
+
+use sp_runtime::RuntimeDebug;
+
+use opensquare_primitives::SdDigest;
+use ospallet_bounties::BountyCategory;
+
+/// A skill claimed by the account, it's verified once the account resolved a bounty of this category.
+#[derive(PartialEq, Eq, Clone, Copy, Encode, Decode, RuntimeDebug)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+pub struct SkillTag {
+    pub category: BountyCategory,
+    pub verified: bool,
+}
+
+#[derive(PartialEq, Eq, Clone, Default, Encode, Decode, RuntimeDebug)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+pub struct Profile {
+    pub display_name: Vec<u8>,
+    /// digest of the profile details stored off chain
+    pub digest: SdDigest,
+    pub skills: Vec<SkillTag>,
+}
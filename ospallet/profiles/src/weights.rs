@@ -0,0 +1,38 @@
+//! Weights for ospallet_profiles.
+//!
+//! `()` implements `WeightInfo` with the weights used in tests and chains without their own
+//! benchmark results. Runtimes should use the weights generated by the `benchmark` subcommand.
+//!
+//! Components:
+//! - `s`: claimed skills, bounded by the number of bounty categories
+
+#![allow(unused_parens)]
+
+use frame_support::weights::{constants::RocksDbWeight as DbWeight, Weight};
+
+pub trait WeightInfo {
+    fn set_profile(s: u32) -> Weight;
+    fn clear_profile() -> Weight;
+    fn note_resolved() -> Weight;
+}
+
+impl WeightInfo for () {
+    fn set_profile(s: u32) -> Weight {
+        (30_000_000 as Weight)
+            .saturating_add((4_000_000 as Weight).saturating_mul(s as Weight))
+            .saturating_add(DbWeight::get().reads(2 as Weight))
+            .saturating_add(DbWeight::get().reads((1 as Weight).saturating_mul(s as Weight)))
+            .saturating_add(DbWeight::get().writes(1 as Weight))
+            .saturating_add(DbWeight::get().writes((2 as Weight).saturating_mul(s as Weight)))
+    }
+    fn clear_profile() -> Weight {
+        (35_000_000 as Weight)
+            .saturating_add(DbWeight::get().reads(1 as Weight))
+            .saturating_add(DbWeight::get().writes(4 as Weight))
+    }
+    fn note_resolved() -> Weight {
+        (20_000_000 as Weight)
+            .saturating_add(DbWeight::get().reads(1 as Weight))
+            .saturating_add(DbWeight::get().writes(2 as Weight))
+    }
+}
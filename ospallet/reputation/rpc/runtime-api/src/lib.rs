@@ -0,0 +1,33 @@
+//! Runtime API definition for the reputation module.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+#![allow(clippy::too_many_arguments, clippy::unnecessary_mut_passed)]
+
+use codec::Codec;
+use sp_std::prelude::*;
+
+use ospallet_reputation::{
+    BountyCategory, HistoryEntry, ReputationBreakdown, ReputationScore, Role, ScoreTable,
+};
+
+sp_api::decl_runtime_apis! {
+    pub trait ReputationApi<AccountId, BlockNumber> where
+        AccountId: Codec,
+        BlockNumber: Codec,
+    {
+        /// Get the total reputation of an account and its score in each bounty category.
+        fn reputation_breakdown(who: AccountId) -> ReputationBreakdown;
+
+        /// Get the raw and the decayed score of an account as a role.
+        fn reputation_score(who: AccountId, role: Role) -> ReputationScore;
+
+        /// Get the score of each behavior for a role.
+        fn score_table(role: Role) -> ScoreTable;
+
+        /// Get the latest reputation changes of an account with their causes, the oldest first.
+        fn reputation_history(who: AccountId) -> Vec<HistoryEntry<AccountId, BlockNumber>>;
+
+        /// Get the score endorsed by other accounts for each skill of an account.
+        fn endorsement_scores(who: AccountId) -> Vec<(BountyCategory, i128)>;
+    }
+}
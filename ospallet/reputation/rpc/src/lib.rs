@@ -0,0 +1,149 @@
+//! RPC interface for the reputation module.
+
+use std::marker::PhantomData;
+use std::sync::Arc;
+
+use codec::Codec;
+use jsonrpc_core::{Error as RpcError, ErrorCode, Result};
+use jsonrpc_derive::rpc;
+use sp_api::ProvideRuntimeApi;
+use sp_blockchain::HeaderBackend;
+use sp_runtime::{generic::BlockId, traits::Block as BlockT};
+
+use ospallet_reputation::{
+    BountyCategory, HistoryEntry, ReputationBreakdown, ReputationScore, Role, ScoreTable,
+};
+pub use ospallet_reputation_rpc_runtime_api::ReputationApi as ReputationRuntimeApi;
+
+#[rpc]
+pub trait ReputationApi<BlockHash, AccountId, BlockNumber> {
+    /// Get the total reputation of an account and its score in each bounty category.
+    #[rpc(name = "reputation_breakdown")]
+    fn reputation_breakdown(
+        &self,
+        who: AccountId,
+        at: Option<BlockHash>,
+    ) -> Result<ReputationBreakdown>;
+
+    /// Get the raw and the decayed score of an account as a role.
+    #[rpc(name = "reputation_score")]
+    fn reputation_score(
+        &self,
+        who: AccountId,
+        role: Role,
+        at: Option<BlockHash>,
+    ) -> Result<ReputationScore>;
+
+    /// Get the score of each behavior for a role.
+    #[rpc(name = "reputation_score_table")]
+    fn score_table(&self, role: Role, at: Option<BlockHash>) -> Result<ScoreTable>;
+
+    /// Get the latest reputation changes of an account with their causes, the oldest first.
+    #[rpc(name = "reputation_history")]
+    fn reputation_history(
+        &self,
+        who: AccountId,
+        at: Option<BlockHash>,
+    ) -> Result<Vec<HistoryEntry<AccountId, BlockNumber>>>;
+
+    /// Get the score endorsed by other accounts for each skill of an account.
+    #[rpc(name = "reputation_endorsement_scores")]
+    fn endorsement_scores(
+        &self,
+        who: AccountId,
+        at: Option<BlockHash>,
+    ) -> Result<Vec<(BountyCategory, i128)>>;
+}
+
+/// Error code of a failed runtime api call.
+const RUNTIME_ERROR: i64 = 1;
+
+fn runtime_error<E: std::fmt::Debug>(message: &str, e: E) -> RpcError {
+    RpcError {
+        code: ErrorCode::ServerError(RUNTIME_ERROR),
+        message: message.into(),
+        data: Some(format!("{:?}", e).into()),
+    }
+}
+
+/// Implements the `ReputationApi` with the runtime api of a client.
+pub struct Reputation<C, B> {
+    client: Arc<C>,
+    _marker: PhantomData<B>,
+}
+
+impl<C, B> Reputation<C, B> {
+    pub fn new(client: Arc<C>) -> Self {
+        Reputation {
+            client,
+            _marker: Default::default(),
+        }
+    }
+}
+
+impl<C, Block, AccountId, BlockNumber>
+    ReputationApi<<Block as BlockT>::Hash, AccountId, BlockNumber> for Reputation<C, Block>
+where
+    Block: BlockT,
+    C: Send + Sync + 'static + ProvideRuntimeApi<Block> + HeaderBackend<Block>,
+    C::Api: ReputationRuntimeApi<Block, AccountId, BlockNumber>,
+    AccountId: Codec,
+    BlockNumber: Codec,
+{
+    fn reputation_breakdown(
+        &self,
+        who: AccountId,
+        at: Option<<Block as BlockT>::Hash>,
+    ) -> Result<ReputationBreakdown> {
+        let api = self.client.runtime_api();
+        let at = BlockId::hash(at.unwrap_or_else(|| self.client.info().best_hash));
+
+        api.reputation_breakdown(&at, who)
+            .map_err(|e| runtime_error("Unable to query the reputation breakdown.", e))
+    }
+
+    fn reputation_score(
+        &self,
+        who: AccountId,
+        role: Role,
+        at: Option<<Block as BlockT>::Hash>,
+    ) -> Result<ReputationScore> {
+        let api = self.client.runtime_api();
+        let at = BlockId::hash(at.unwrap_or_else(|| self.client.info().best_hash));
+
+        api.reputation_score(&at, who, role)
+            .map_err(|e| runtime_error("Unable to query the reputation score.", e))
+    }
+
+    fn score_table(&self, role: Role, at: Option<<Block as BlockT>::Hash>) -> Result<ScoreTable> {
+        let api = self.client.runtime_api();
+        let at = BlockId::hash(at.unwrap_or_else(|| self.client.info().best_hash));
+
+        api.score_table(&at, role)
+            .map_err(|e| runtime_error("Unable to query the score table.", e))
+    }
+
+    fn reputation_history(
+        &self,
+        who: AccountId,
+        at: Option<<Block as BlockT>::Hash>,
+    ) -> Result<Vec<HistoryEntry<AccountId, BlockNumber>>> {
+        let api = self.client.runtime_api();
+        let at = BlockId::hash(at.unwrap_or_else(|| self.client.info().best_hash));
+
+        api.reputation_history(&at, who)
+            .map_err(|e| runtime_error("Unable to query the reputation history.", e))
+    }
+
+    fn endorsement_scores(
+        &self,
+        who: AccountId,
+        at: Option<<Block as BlockT>::Hash>,
+    ) -> Result<Vec<(BountyCategory, i128)>> {
+        let api = self.client.runtime_api();
+        let at = BlockId::hash(at.unwrap_or_else(|| self.client.info().best_hash));
+
+        api.endorsement_scores(&at, who)
+            .map_err(|e| runtime_error("Unable to query the endorsement scores.", e))
+    }
+}
@@ -0,0 +1,142 @@
+//! Benchmarks for ospallet_reputation.
+
+#![cfg(feature = "runtime-benchmarks")]
+
+use frame_benchmarking::{account, benchmarks};
+use frame_support::traits::{Currency, EnsureOrigin, Get, UnfilteredDispatchable};
+use frame_system::RawOrigin;
+use sp_runtime::traits::Saturating;
+
+use opensquare_primitives::{BountyCategory, BountyId};
+
+use crate::{
+    Behavior, BountyContext, BountyRemarkCollaborationResult, Call, Module, ReputationBuilder,
+    Role, ScoreTable, Trait,
+};
+
+const SEED: u32 = 0;
+
+/// Remark the hunter `Bad` in the bounty.
+fn remarked<T: Trait>(bounty_id: BountyId) -> (T::AccountId, T::AccountId) {
+    let hunter: T::AccountId = account("hunter", 0, SEED);
+    let funder: T::AccountId = account("funder", 0, SEED);
+    let deposit = T::AppealDeposit::get();
+    T::Currency::make_free_balance_be(&hunter, deposit.saturating_mul(10u32.into()));
+    let context = BountyContext {
+        bounty_id,
+        category: BountyCategory::Development,
+        counterparty: funder.clone(),
+        value: 0,
+    };
+    Module::<T>::add_behavior_score_by_behavior(
+        &hunter,
+        Role::Hunter,
+        &Behavior::BountyRemark(BountyRemarkCollaborationResult::Bad),
+        &context,
+    );
+    (hunter, funder)
+}
+
+/// Endorser with enough reputation for the max endorsement weight.
+fn endorser<T: Trait>() -> T::AccountId {
+    let endorser: T::AccountId = account("endorser", 0, SEED);
+    let reputation =
+        Module::<T>::endorsement_unit().saturating_mul(Module::<T>::max_endorsement_weight());
+    Module::<T>::add_behavior_score(&endorser, Role::Hunter, reputation);
+    endorser
+}
+
+benchmarks! {
+    _ { }
+
+    set_score_table {
+        let origin = T::CouncilOrigin::successful_origin();
+        let table = ScoreTable {
+            resolve_success: 20,
+            ..ScoreTable::HUNTER
+        };
+        let call = Call::<T>::set_score_table(Role::Hunter, table);
+    }: { call.dispatch_bypass_filter(origin)? }
+    verify {
+        assert_eq!(Module::<T>::hunter_score_table(), table);
+    }
+
+    set_decay_half_life {
+        let origin = T::CouncilOrigin::successful_origin();
+        let half_life: T::BlockNumber = 100u32.into();
+        let call = Call::<T>::set_decay_half_life(Some(half_life));
+    }: { call.dispatch_bypass_filter(origin)? }
+    verify {
+        assert_eq!(Module::<T>::decay_half_life(), Some(half_life));
+    }
+
+    set_penalties {
+        let origin = T::CouncilOrigin::successful_origin();
+        let call = Call::<T>::set_penalties(-10, -2);
+    }: { call.dispatch_bypass_filter(origin)? }
+    verify {
+        assert_eq!(Module::<T>::reversal_penalty(), -10);
+    }
+
+    set_value_weighting {
+        let origin = T::CouncilOrigin::successful_origin();
+        let call = Call::<T>::set_value_weighting(100, 4, 20);
+    }: { call.dispatch_bypass_filter(origin)? }
+    verify {
+        assert_eq!(Module::<T>::unit_value(), 100);
+    }
+
+    set_endorsement_limits {
+        let origin = T::CouncilOrigin::successful_origin();
+        let call = Call::<T>::set_endorsement_limits(20, 3);
+    }: { call.dispatch_bypass_filter(origin)? }
+    verify {
+        assert_eq!(Module::<T>::max_endorsement_weight(), 3);
+    }
+
+    appeal_remark {
+        let (hunter, _) = remarked::<T>(Default::default());
+    }: _(RawOrigin::Signed(hunter.clone()), Default::default(), Default::default())
+    verify {
+        assert!(Module::<T>::appeals(&hunter, BountyId::default()).is_some());
+    }
+
+    // reversing costs more than upholding
+    resolve_appeal {
+        let (hunter, _) = remarked::<T>(Default::default());
+        Module::<T>::appeal_remark(
+            RawOrigin::Signed(hunter.clone()).into(),
+            Default::default(),
+            Default::default(),
+        )?;
+        let origin = T::CouncilOrigin::successful_origin();
+        let call = Call::<T>::resolve_appeal(hunter.clone(), Default::default(), true);
+    }: { call.dispatch_bypass_filter(origin)? }
+    verify {
+        assert!(Module::<T>::appeals(&hunter, BountyId::default()).is_none());
+    }
+
+    endorse {
+        let endorser = endorser::<T>();
+        let hunter: T::AccountId = account("hunter", 0, SEED);
+    }: _(RawOrigin::Signed(endorser), hunter.clone(), BountyCategory::Development)
+    verify {
+        assert_eq!(
+            Module::<T>::endorsement_score(&hunter, BountyCategory::Development),
+            Module::<T>::max_endorsement_weight()
+        );
+    }
+
+    revoke_endorsement {
+        let endorser = endorser::<T>();
+        let hunter: T::AccountId = account("hunter", 0, SEED);
+        Module::<T>::endorse(
+            RawOrigin::Signed(endorser.clone()).into(),
+            hunter.clone(),
+            BountyCategory::Development,
+        )?;
+    }: _(RawOrigin::Signed(endorser), hunter.clone(), BountyCategory::Development)
+    verify {
+        assert_eq!(Module::<T>::endorsement_score(&hunter, BountyCategory::Development), 0);
+    }
+}
@@ -1,66 +1,670 @@
 #![cfg_attr(not(feature = "std"), no_std)]
 
-use frame_support::{decl_event, decl_module, decl_storage};
-use frame_system as system;
+use frame_support::{
+    decl_error, decl_event, decl_module, decl_storage,
+    dispatch::DispatchResult,
+    ensure,
+    storage::{
+        migration::{put_storage_value, StorageIterator},
+        IterableStorageDoubleMap,
+    },
+    traits::{Currency, EnsureOrigin, Get, ReservableCurrency},
+    weights::Weight,
+};
+use frame_system::{self as system, ensure_signed};
+use sp_runtime::traits::{SaturatedConversion, Saturating, Zero};
+use sp_std::{marker::PhantomData, prelude::*};
+
+use opensquare_primitives::{BountyId, SdDigest, BLOCKS_PER_DAY};
+
+pub use opensquare_primitives::BountyCategory;
 
 pub use crate::types::{
-    Behavior, BountyRemarkCollaborationResult, BountyResolveCollaborationResult, ReputationBuilder,
+    Appeal, AppealStatus, Behavior, BountyContext, BountyRemarkCollaborationResult,
+    BountyResolveCollaborationResult, DecayedScore, HistoryEntry, OnBehavior, Releases,
+    ReputationBreakdown, ReputationBuilder, ReputationScore, Role, ScoreTable, WeighReputation,
 };
 
+#[cfg(feature = "runtime-benchmarks")]
+mod benchmarking;
 mod types;
+mod weights;
+
+pub use weights::WeightInfo;
 
 pub trait Trait: system::Trait {
     type Event: From<Event<Self>> + Into<<Self as frame_system::Trait>::Event>;
+
+    /// The currency used to reserve the appeal deposit.
+    type Currency: ReservableCurrency<Self::AccountId>;
+
+    /// Origin which could update the score tables and the decay, and resolve appeals
+    type CouncilOrigin: EnsureOrigin<Self::Origin>;
+
+    /// Deposit reserved from the appellant, refunded if the remark is reversed and slashed if upheld.
+    type AppealDeposit: Get<BalanceOf<Self>>;
+
+    /// Weigh behavior scores by their bounties
+    type WeighReputation: WeighReputation<Self::AccountId>;
+
+    /// Handler of behaviors added to accounts
+    type OnBehavior: OnBehavior<Self::AccountId>;
+
+    /// Weight information for extrinsics in this pallet.
+    type WeightInfo: WeightInfo;
+}
+
+pub type BalanceOf<T> =
+    <<T as Trait>::Currency as Currency<<T as frame_system::Trait>::AccountId>>::Balance;
+
+pub type HistoryEntryOf<T> =
+    HistoryEntry<<T as frame_system::Trait>::AccountId, <T as frame_system::Trait>::BlockNumber>;
+pub type AppealOf<T> = Appeal<
+    <T as frame_system::Trait>::AccountId,
+    BalanceOf<T>,
+    <T as frame_system::Trait>::BlockNumber,
+>;
+
+decl_error! {
+    pub enum Error for Module<T: Trait> {
+        /// Half-life should be larger than zero
+        InvalidHalfLife,
+        /// no appealable remark on the account for the bounty
+        RemarkNotFound,
+        /// the remark is older than the appeal period
+        AppealPeriodOver,
+        /// the remark has been appealed
+        AlreadyAppealed,
+        /// no appeal of the account for the bounty
+        AppealNotFound,
+        /// an account could not endorse itself
+        SelfEndorsement,
+        /// the skill of the account has been endorsed by the endorser
+        AlreadyEndorsed,
+        /// no endorsement of the skill by the endorser
+        EndorsementNotFound,
+        /// the endorser's reputation is too low to bring any weight
+        EndorserReputationTooLow,
+        /// the endorser has given the max endorsement weight to the account
+        EndorsementLimitReached,
+        /// Penalties should not be positive
+        InvalidPenalty,
+        /// Unit value and max value multiplier should be larger than zero
+        InvalidValueWeighting,
+        /// Endorsement unit and max endorsement weight should be larger than zero
+        InvalidEndorsementLimits,
+    }
 }
 
 decl_event!(
     pub enum Event<T> where
         <T as frame_system::Trait>::AccountId,
+        <T as frame_system::Trait>::BlockNumber,
     {
-        ReputationAdded(AccountId, i128),
+        ReputationAdded(AccountId, Role, i128),
+        CategoryReputationAdded(AccountId, BountyCategory, i128),
+        ScoreTableSet(Role, ScoreTable),
+        DecayHalfLifeSet(Option<BlockNumber>),
+        RemarkAppealed(AccountId, BountyId, SdDigest),
+        AppealResolved(AccountId, BountyId, AppealStatus),
+        /// endorser, endorsed, skill, weight
+        Endorsed(AccountId, AccountId, BountyCategory, i128),
+        /// endorser, endorsed, skill
+        EndorsementRevoked(AccountId, AccountId, BountyCategory),
+        /// reversal penalty, extension penalty
+        PenaltiesSet(i128, i128),
+        /// unit value, max value multiplier, low reputation threshold
+        ValueWeightingSet(u128, u32, i128),
+        /// endorsement unit, max endorsement weight
+        EndorsementLimitsSet(i128, i128),
     }
 );
 
 decl_storage! {
     trait Store for Module<T: Trait> as OsReputation {
-        pub BehaviorScore get(fn behavior_score): map hasher(blake2_128_concat) T::AccountId => i128;
+        /// Behavior score as the hunter
+        pub HunterScore get(fn hunter_score): map hasher(blake2_128_concat) T::AccountId => i128;
+        /// Behavior score as the funder
+        pub FunderScore get(fn funder_score): map hasher(blake2_128_concat) T::AccountId => i128;
+        /// Hunter score gained in bounties of a category, the sum of them equals to `HunterScore`
+        pub CategoryScore get(fn category_score):
+            double_map hasher(blake2_128_concat) T::AccountId, hasher(twox_64_concat) BountyCategory => i128;
+        /// Score endorsed by other accounts for a skill, kept apart from the behavior scores
+        pub EndorsementScore get(fn endorsement_score):
+            double_map hasher(blake2_128_concat) T::AccountId, hasher(twox_64_concat) BountyCategory => i128;
+        /// Weight of each endorsement on an account, keyed by the endorser and the skill
+        pub Endorsements get(fn endorsements):
+            double_map hasher(blake2_128_concat) T::AccountId, hasher(blake2_128_concat) (T::AccountId, BountyCategory)
+                => Option<i128>;
+        /// Endorser reputation for each weight of an endorsement
+        pub EndorsementUnit get(fn endorsement_unit) config(): i128 = 10;
+        /// Total weight of the endorsements of an endorser on an account, keyed by the account and the endorser
+        pub EndorsedWeight get(fn endorsed_weight):
+            double_map hasher(blake2_128_concat) T::AccountId, hasher(blake2_128_concat) T::AccountId => i128;
+        /// Max weight of an endorsement, and of all endorsements of an endorser on an account
+        pub MaxEndorsementWeight get(fn max_endorsement_weight) config(): i128 = 5;
+
+        /// Score of each behavior for hunters
+        pub HunterScoreTable get(fn hunter_score_table) config(): ScoreTable = ScoreTable::HUNTER;
+        /// Score of each behavior for funders
+        pub FunderScoreTable get(fn funder_score_table) config(): ScoreTable = ScoreTable::FUNDER;
+
+        /// Blocks for a score to decay to half, scores don't decay if it's none
+        pub DecayHalfLife get(fn decay_half_life) config(): Option<T::BlockNumber>;
+        /// Decayed score of each role, the undecayed score is in `HunterScore` and `FunderScore`
+        pub DecayedScores get(fn decayed_scores):
+            double_map hasher(blake2_128_concat) T::AccountId, hasher(twox_64_concat) Role
+                => Option<DecayedScore<T::BlockNumber>>;
+
+        /// Latest reputation changes of an account with their causes, the oldest first
+        pub History get(fn history):
+            map hasher(blake2_128_concat) T::AccountId => Vec<HistoryEntryOf<T>>;
+        /// Max entries kept in the history of an account
+        pub MaxHistoryLength get(fn max_history_length): u32 = 50;
+
+        /// Bounties resolved by an account with the counterparty
+        pub Collaborations get(fn collaborations):
+            double_map hasher(blake2_128_concat) T::AccountId, hasher(blake2_128_concat) T::AccountId => u32;
+
+        /// Bounty value which brings the score in the table, used by `ValueWeighted`
+        pub UnitValue get(fn unit_value) config(): u128 = 10_000_000_000_000_000;
+        /// Max multiple of the table score for valuable bounties, used by `ValueWeighted`
+        pub MaxValueMultiplier get(fn max_value_multiplier) config(): u32 = 8;
+        /// Counterparties under this score are taken as low reputation, used by `ValueWeighted`
+        pub LowReputationThreshold get(fn low_reputation_threshold) config(): i128 = 10;
+
+        /// Remarks costing score which could still be appealed, kept until `AppealPeriod` is over
+        pub AppealableRemarks get(fn appealable_remarks):
+            double_map hasher(blake2_128_concat) T::AccountId, hasher(identity) BountyId => Option<HistoryEntryOf<T>>;
+        /// Appealable remarks dropped at a block
+        pub RemarkExpiries get(fn remark_expiries):
+            map hasher(twox_64_concat) T::BlockNumber => Vec<(T::AccountId, BountyId)>;
+        /// Pending appeals of remarked accounts against the remarks of a bounty, removed once resolved
+        pub Appeals get(fn appeals):
+            double_map hasher(blake2_128_concat) T::AccountId, hasher(identity) BountyId => Option<AppealOf<T>>;
+        /// Blocks after a remark in which it could be appealed
+        pub AppealPeriod get(fn appeal_period) config(): T::BlockNumber = (7 * BLOCKS_PER_DAY).saturated_into();
+        /// Score added to the remarker of a reversed remark
+        pub ReversalPenalty get(fn reversal_penalty) config(): i128 = -5;
+        /// Score added to a hunter for each approved extension beyond the free ones of a bounty
+        pub ExtensionPenalty get(fn extension_penalty) config(): i128 = -1;
+
+        StorageVersion get(fn storage_version): Releases;
     }
+        add_extra_genesis {
+            build(|_| {
+                StorageVersion::put(Releases::V2_0_0);
+            })
+        }
 }
 
 decl_module! {
     pub struct Module<T: Trait> for enum Call where origin: T::Origin {
+        type Error = Error<T>;
+
         fn deposit_event() = default;
+
+        /// Replace the score table of a role, scores already gained are not changed.
+        #[weight = T::WeightInfo::set_score_table()]
+        fn set_score_table(origin, role: Role, table: ScoreTable) -> DispatchResult {
+            T::CouncilOrigin::ensure_origin(origin)?;
+
+            match role {
+                Role::Hunter => HunterScoreTable::put(table),
+                Role::Funder => FunderScoreTable::put(table),
+            }
+            Self::deposit_event(RawEvent::ScoreTableSet(role, table));
+            Ok(())
+        }
+
+        /// Set the half-life of scores in blocks, or stop the decay with none.
+        ///
+        /// The new half-life applies to the blocks since each account's score was last updated.
+        #[weight = T::WeightInfo::set_decay_half_life()]
+        fn set_decay_half_life(origin, half_life: Option<T::BlockNumber>) -> DispatchResult {
+            T::CouncilOrigin::ensure_origin(origin)?;
+            ensure!(half_life.map_or(true, |h| !h.is_zero()), Error::<T>::InvalidHalfLife);
+
+            DecayHalfLife::<T>::set(half_life);
+            Self::deposit_event(RawEvent::DecayHalfLifeSet(half_life));
+            Ok(())
+        }
+
+        /// Appeal a remark costing score within `AppealPeriod` blocks, with the digest of the evidence.
+        ///
+        /// `AppealDeposit` is reserved until the council resolves the appeal.
+        #[weight = T::WeightInfo::appeal_remark()]
+        fn appeal_remark(origin, bounty_id: BountyId, evidence: SdDigest) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+            ensure!(!Appeals::<T>::contains_key(&who, bounty_id), Error::<T>::AlreadyAppealed);
+
+            let entry = Self::appealable_remarks(&who, bounty_id).ok_or(Error::<T>::RemarkNotFound)?;
+            let now = system::Module::<T>::block_number();
+            ensure!(
+                now < entry.block_number.saturating_add(Self::appeal_period()),
+                Error::<T>::AppealPeriodOver
+            );
+
+            let deposit = T::AppealDeposit::get();
+            T::Currency::reserve(&who, deposit)?;
+            AppealableRemarks::<T>::remove(&who, bounty_id);
+            Appeals::<T>::insert(
+                &who,
+                bounty_id,
+                Appeal {
+                    entry,
+                    evidence,
+                    deposit,
+                },
+            );
+            Self::deposit_event(RawEvent::RemarkAppealed(who, bounty_id, evidence));
+            Ok(())
+        }
+
+        /// Uphold or reverse an appealed remark.
+        ///
+        /// A reversed remark is undone and the remarker is penalized by `ReversalPenalty`, and the
+        /// deposit is refunded. The deposit is slashed if the remark is upheld.
+        #[weight = T::WeightInfo::resolve_appeal().saturating_add(T::OnBehavior::on_behavior_weight().saturating_mul(2))]
+        fn resolve_appeal(origin, who: T::AccountId, bounty_id: BountyId, reverse: bool) -> DispatchResult {
+            T::CouncilOrigin::ensure_origin(origin)?;
+            let appeal = Appeals::<T>::take(&who, bounty_id).ok_or(Error::<T>::AppealNotFound)?;
+
+            let status = if reverse {
+                T::Currency::unreserve(&who, appeal.deposit);
+                Self::reverse_remark(&who, &appeal.entry);
+                AppealStatus::Reversed
+            } else {
+                let _ = T::Currency::slash_reserved(&who, appeal.deposit);
+                AppealStatus::Upheld
+            };
+            Self::deposit_event(RawEvent::AppealResolved(who, bounty_id, status));
+            Ok(())
+        }
+
+        /// Endorse a skill of an account, weighted by the reputation of the endorser.
+        ///
+        /// The weight is fixed when endorsing. The endorsements of an endorser on an account weigh at
+        /// most `MaxEndorsementWeight` in total, over all skills.
+        #[weight = T::WeightInfo::endorse()]
+        fn endorse(origin, who: T::AccountId, skill: BountyCategory) -> DispatchResult {
+            let endorser = ensure_signed(origin)?;
+            ensure!(endorser != who, Error::<T>::SelfEndorsement);
+            let key = (endorser.clone(), skill);
+            ensure!(!Endorsements::<T>::contains_key(&who, &key), Error::<T>::AlreadyEndorsed);
+
+            let weight = Self::endorsement_weight(&endorser);
+            ensure!(weight > 0, Error::<T>::EndorserReputationTooLow);
+            let given = Self::endorsed_weight(&who, &endorser);
+            let weight = weight.min(Self::max_endorsement_weight().saturating_sub(given));
+            ensure!(weight > 0, Error::<T>::EndorsementLimitReached);
+
+            Endorsements::<T>::insert(&who, &key, weight);
+            EndorsedWeight::<T>::insert(&who, &endorser, given.saturating_add(weight));
+            EndorsementScore::<T>::mutate(&who, skill, |score| *score = score.saturating_add(weight));
+            Self::deposit_event(RawEvent::Endorsed(endorser, who, skill, weight));
+            Ok(())
+        }
+
+        /// Revoke an endorsement, its weight is taken back from the endorsed account.
+        #[weight = T::WeightInfo::revoke_endorsement()]
+        fn revoke_endorsement(origin, who: T::AccountId, skill: BountyCategory) -> DispatchResult {
+            let endorser = ensure_signed(origin)?;
+            let weight = Endorsements::<T>::take(&who, (endorser.clone(), skill))
+                .ok_or(Error::<T>::EndorsementNotFound)?;
+
+            let given = Self::endorsed_weight(&who, &endorser).saturating_sub(weight);
+            if given > 0 {
+                EndorsedWeight::<T>::insert(&who, &endorser, given);
+            } else {
+                EndorsedWeight::<T>::remove(&who, &endorser);
+            }
+            EndorsementScore::<T>::mutate(&who, skill, |score| *score = score.saturating_sub(weight));
+            Self::deposit_event(RawEvent::EndorsementRevoked(endorser, who, skill));
+            Ok(())
+        }
+
+        /// Set `ReversalPenalty` and `ExtensionPenalty`, scores already gained are not changed.
+        #[weight = T::WeightInfo::set_penalties()]
+        fn set_penalties(origin, reversal_penalty: i128, extension_penalty: i128) -> DispatchResult {
+            T::CouncilOrigin::ensure_origin(origin)?;
+            ensure!(reversal_penalty <= 0 && extension_penalty <= 0, Error::<T>::InvalidPenalty);
+
+            ReversalPenalty::put(reversal_penalty);
+            ExtensionPenalty::put(extension_penalty);
+            Self::deposit_event(RawEvent::PenaltiesSet(reversal_penalty, extension_penalty));
+            Ok(())
+        }
+
+        /// Set the parameters of `ValueWeighted`, they apply to later behaviors.
+        #[weight = T::WeightInfo::set_value_weighting()]
+        fn set_value_weighting(origin, unit_value: u128, max_value_multiplier: u32, low_reputation_threshold: i128) -> DispatchResult {
+            T::CouncilOrigin::ensure_origin(origin)?;
+            ensure!(unit_value > 0 && max_value_multiplier > 0, Error::<T>::InvalidValueWeighting);
+
+            UnitValue::put(unit_value);
+            MaxValueMultiplier::put(max_value_multiplier);
+            LowReputationThreshold::put(low_reputation_threshold);
+            Self::deposit_event(RawEvent::ValueWeightingSet(unit_value, max_value_multiplier, low_reputation_threshold));
+            Ok(())
+        }
+
+        /// Set `EndorsementUnit` and `MaxEndorsementWeight`, weights of given endorsements are kept.
+        #[weight = T::WeightInfo::set_endorsement_limits()]
+        fn set_endorsement_limits(origin, endorsement_unit: i128, max_endorsement_weight: i128) -> DispatchResult {
+            T::CouncilOrigin::ensure_origin(origin)?;
+            ensure!(endorsement_unit > 0 && max_endorsement_weight > 0, Error::<T>::InvalidEndorsementLimits);
+
+            EndorsementUnit::put(endorsement_unit);
+            MaxEndorsementWeight::put(max_endorsement_weight);
+            Self::deposit_event(RawEvent::EndorsementLimitsSet(endorsement_unit, max_endorsement_weight));
+            Ok(())
+        }
+
+        fn on_initialize(now: T::BlockNumber) -> Weight {
+            let expired = RemarkExpiries::<T>::take(now);
+            for (who, bounty_id) in expired.iter() {
+                AppealableRemarks::<T>::remove(who, bounty_id);
+            }
+            T::DbWeight::get().reads_writes(1, expired.len() as Weight + 1)
+        }
+
+        fn on_runtime_upgrade() -> Weight {
+            if Self::storage_version() == Releases::V1_0_0 {
+                let migrated = Self::migrate_to_role_tracks();
+                StorageVersion::put(Releases::V2_0_0);
+                T::DbWeight::get().reads_writes(migrated + 1, migrated * 2 + 1)
+            } else {
+                0
+            }
+        }
     }
 }
 
 impl<T: Trait> Module<T> {
-    pub fn add_behavior_score(target: &T::AccountId, score: i128) {
-        BehaviorScore::<T>::mutate(target, |pre| {
+    pub fn add_behavior_score(target: &T::AccountId, role: Role, score: i128) {
+        let raw = Self::raw_score(target, role);
+        let new_score = match raw.checked_add(score) {
+            Some(new_score) => new_score,
+            None => return,
+        };
+
+        let now = system::Module::<T>::block_number();
+        let decayed = Self::decayed_score(target, role);
+        DecayedScores::<T>::insert(
+            target,
+            role,
+            DecayedScore {
+                score: decayed.saturating_add(score),
+                updated_at: now,
+            },
+        );
+        match role {
+            Role::Hunter => HunterScore::<T>::insert(target, new_score),
+            Role::Funder => FunderScore::<T>::insert(target, new_score),
+        }
+        Self::deposit_event(RawEvent::ReputationAdded(target.clone(), role, new_score));
+    }
+
+    pub fn raw_score(who: &T::AccountId, role: Role) -> i128 {
+        match role {
+            Role::Hunter => Self::hunter_score(who),
+            Role::Funder => Self::funder_score(who),
+        }
+    }
+
+    /// Score of a role decayed to the current block.
+    ///
+    /// Scores gained before the decay was introduced have no record, they start to decay from
+    /// their next update.
+    pub fn decayed_score(who: &T::AccountId, role: Role) -> i128 {
+        match Self::decayed_scores(who, role) {
+            Some(record) => {
+                let now = system::Module::<T>::block_number();
+                Self::decay(record.score, now.saturating_sub(record.updated_at))
+            }
+            None => Self::raw_score(who, role),
+        }
+    }
+
+    /// Raw and decayed score of a role.
+    pub fn reputation_score(who: &T::AccountId, role: Role) -> ReputationScore {
+        ReputationScore {
+            raw: Self::raw_score(who, role),
+            decayed: Self::decayed_score(who, role),
+        }
+    }
+
+    /// Append an entry to the history of `who`, the oldest entries are dropped once it's full.
+    fn record_history(who: &T::AccountId, entry: HistoryEntryOf<T>) {
+        let max = Self::max_history_length() as usize;
+        History::<T>::mutate(who, |history| {
+            history.push(entry);
+            if history.len() > max {
+                let excess = history.len() - max;
+                history.drain(..excess);
+            }
+        });
+    }
+
+    /// Add a weighted score to the role, and record it in the history.
+    fn apply_score(
+        target: &T::AccountId,
+        role: Role,
+        behavior: &Behavior,
+        score: i128,
+        context: &BountyContext<T::AccountId>,
+    ) {
+        Self::add_behavior_score(target, role, score);
+        let entry = HistoryEntry {
+            role,
+            behavior: *behavior,
+            bounty_id: context.bounty_id,
+            category: context.category,
+            counterparty: context.counterparty.clone(),
+            score,
+            block_number: system::Module::<T>::block_number(),
+        };
+        if let Behavior::BountyRemark(_) = behavior {
+            if score < 0 {
+                Self::note_appealable_remark(target, entry.clone());
+            }
+        }
+        Self::record_history(target, entry);
+        if role == Role::Hunter {
+            Self::add_category_score(target, context.category, score);
+        }
+        T::OnBehavior::on_behavior(target, role, behavior, context);
+    }
+
+    /// Keep a remark costing score until it could no longer be appealed.
+    fn note_appealable_remark(who: &T::AccountId, entry: HistoryEntryOf<T>) {
+        let bounty_id = entry.bounty_id;
+        let expiry = entry.block_number.saturating_add(Self::appeal_period());
+        AppealableRemarks::<T>::insert(who, bounty_id, entry);
+        RemarkExpiries::<T>::append(expiry, (who.clone(), bounty_id));
+    }
+
+    /// Give back the score taken by a reversed remark, and penalize the remarker.
+    fn reverse_remark(who: &T::AccountId, entry: &HistoryEntryOf<T>) {
+        let reversed = match entry.behavior {
+            Behavior::BountyRemark(remark) => Behavior::RemarkReversed { remark },
+            _ => return,
+        };
+        let context = BountyContext {
+            bounty_id: entry.bounty_id,
+            category: entry.category,
+            counterparty: entry.counterparty.clone(),
+            value: 0,
+        };
+        Self::apply_score(
+            who,
+            entry.role,
+            &reversed,
+            entry.score.saturating_neg(),
+            &context,
+        );
+
+        let remarker_role = match entry.role {
+            Role::Hunter => Role::Funder,
+            Role::Funder => Role::Hunter,
+        };
+        <Self as ReputationBuilder<T::AccountId>>::add_behavior_score_by_behavior(
+            &entry.counterparty,
+            remarker_role,
+            &reversed,
+            &BountyContext {
+                counterparty: who.clone(),
+                ..context
+            },
+        );
+    }
+
+    /// Halve `score` every half-life, and interpolate linearly within a half-life.
+    fn decay(score: i128, elapsed: T::BlockNumber) -> i128 {
+        let half_life = match Self::decay_half_life() {
+            Some(half_life) => half_life.saturated_into::<u128>(),
+            None => return score,
+        };
+        let elapsed = elapsed.saturated_into::<u128>();
+        let halvings = elapsed / half_life;
+        if halvings >= 127 {
+            return 0;
+        }
+        let halved = score / (1i128 << halvings);
+        let rest = (elapsed % half_life) as i128;
+        let double_half_life = half_life.saturated_into::<i128>().saturating_mul(2);
+        halved.saturating_mul(double_half_life.saturating_sub(rest)) / double_half_life
+    }
+
+    pub fn add_category_score(target: &T::AccountId, category: BountyCategory, score: i128) {
+        CategoryScore::<T>::mutate(target, category, |pre| {
             if let Some(new_score) = pre.checked_add(score) {
                 *pre = new_score;
-                Self::deposit_event(RawEvent::ReputationAdded(target.clone(), new_score));
+                Self::deposit_event(RawEvent::CategoryReputationAdded(
+                    target.clone(),
+                    category,
+                    new_score,
+                ));
             }
         });
     }
+
+    /// Reputation of an account, with the score of each category it has worked in.
+    pub fn reputation_breakdown(who: &T::AccountId) -> ReputationBreakdown {
+        ReputationBreakdown {
+            hunter: Self::hunter_score(who),
+            funder: Self::funder_score(who),
+            categories: CategoryScore::<T>::iter_prefix(who).collect(),
+        }
+    }
+
+    /// Weight of an endorsement by `endorser`, its decayed hunter and funder score per `EndorsementUnit`.
+    pub fn endorsement_weight(endorser: &T::AccountId) -> i128 {
+        let reputation = Self::decayed_score(endorser, Role::Hunter)
+            .saturating_add(Self::decayed_score(endorser, Role::Funder));
+        (reputation / Self::endorsement_unit().max(1)).min(Self::max_endorsement_weight())
+    }
+
+    /// Endorsed score of each skill of an account.
+    pub fn endorsement_scores(who: &T::AccountId) -> Vec<(BountyCategory, i128)> {
+        EndorsementScore::<T>::iter_prefix(who).collect()
+    }
+
+    pub fn score_table(role: Role) -> ScoreTable {
+        match role {
+            Role::Hunter => Self::hunter_score_table(),
+            Role::Funder => Self::funder_score_table(),
+        }
+    }
+
+    /// Move the single `BehaviorScore` into the hunter track, return the number of migrated accounts.
+    fn migrate_to_role_tracks() -> Weight {
+        let mut migrated: Weight = 0;
+        for (hashed_key, score) in
+            StorageIterator::<i128>::new(b"OsReputation", b"BehaviorScore").drain()
+        {
+            // both maps are hashed by `blake2_128_concat`, so the hashed key could be reused
+            put_storage_value(b"OsReputation", b"HunterScore", &hashed_key, score);
+            migrated += 1;
+        }
+        migrated
+    }
 }
 
 impl<T: Trait> ReputationBuilder<T::AccountId> for Module<T> {
-    // TODO: calc behavior score separately for funder and hunter
-    fn add_behavior_score_by_behavior(target: &T::AccountId, behavior: &Behavior) {
-        let score = Self::get_behavior_score(behavior);
-        Self::add_behavior_score(target, score)
-    }
-
-    fn get_behavior_score(behavior: &Behavior) -> i128 {
-        return match behavior {
-            Behavior::BountyResolve(BountyResolveCollaborationResult::Success) => 10,
-            Behavior::BountyResolve(BountyResolveCollaborationResult::Fail) => -2,
-            Behavior::BountyRemark(BountyRemarkCollaborationResult::Bad) => -2,
-            Behavior::BountyRemark(BountyRemarkCollaborationResult::NotGood) => 0,
-            Behavior::BountyRemark(BountyRemarkCollaborationResult::Fine) => 1,
-            Behavior::BountyRemark(BountyRemarkCollaborationResult::Good) => 3,
-            Behavior::BountyRemark(BountyRemarkCollaborationResult::Perfect) => 5,
+    fn add_behavior_score_by_behavior(
+        target: &T::AccountId,
+        role: Role,
+        behavior: &Behavior,
+        context: &BountyContext<T::AccountId>,
+    ) {
+        let score = T::WeighReputation::weigh(
+            target,
+            role,
+            Self::get_behavior_score(role, behavior),
+            context,
+        );
+        Self::apply_score(target, role, behavior, score, context);
+        if let Behavior::BountyResolve(BountyResolveCollaborationResult::Success) = behavior {
+            Collaborations::<T>::mutate(target, &context.counterparty, |count| {
+                *count = count.saturating_add(1)
+            });
+        }
+    }
+
+    fn get_behavior_score(role: Role, behavior: &Behavior) -> i128 {
+        match behavior {
+            Behavior::RemarkReversed { .. } => Self::reversal_penalty(),
+            Behavior::ExtensionOverused { .. } => Self::extension_penalty(),
+            _ => Self::score_table(role).score_of(behavior),
+        }
+    }
+
+    fn behavior_hooks_weight() -> Weight {
+        T::OnBehavior::on_behavior_weight()
+    }
+}
+
+/// Scale positive scores by the bounty value, and discount them for repeated collaborations and
+/// low reputation counterparties, whose endorsements are counted. Negative scores are kept.
+pub struct ValueWeighted<T>(PhantomData<T>);
+
+impl<T: Trait> WeighReputation<T::AccountId> for ValueWeighted<T> {
+    fn weigh(
+        target: &T::AccountId,
+        _role: Role,
+        score: i128,
+        context: &BountyContext<T::AccountId>,
+    ) -> i128 {
+        if score <= 0 {
+            return score;
+        }
+
+        // proportional under the unit value, and grows by log2 above it
+        let unit = Module::<T>::unit_value().max(1);
+        let mut weighted = if context.value < unit {
+            score.saturating_mul(context.value.saturated_into()) / unit.saturated_into::<i128>()
+        } else {
+            let multiplier = (128 - (context.value / unit).leading_zeros())
+                .min(Module::<T>::max_value_multiplier());
+            score.saturating_mul(multiplier.into())
         };
+
+        let repeated = Module::<T>::collaborations(target, &context.counterparty);
+        weighted /= i128::from(repeated).saturating_add(1);
+
+        // endorsements let new accounts out of the low reputation discount
+        let endorsed = Module::<T>::endorsement_scores(&context.counterparty)
+            .into_iter()
+            .fold(0i128, |sum, (_, score)| sum.saturating_add(score));
+        let counterparty_score = Module::<T>::hunter_score(&context.counterparty)
+            .saturating_add(Module::<T>::funder_score(&context.counterparty))
+            .saturating_add(endorsed);
+        if counterparty_score < Module::<T>::low_reputation_threshold() {
+            weighted /= 2;
+        }
+        weighted
     }
 }
 
@@ -68,7 +672,14 @@ impl<T: Trait> ReputationBuilder<T::AccountId> for Module<T> {
 mod tests {
     use super::*;
 
-    use frame_support::{impl_outer_origin, parameter_types, weights::Weight};
+    use codec::Encode;
+    use frame_support::{
+        impl_outer_origin, parameter_types,
+        traits::{OnInitialize, OnRuntimeUpgrade},
+        weights::Weight,
+        StorageHasher,
+    };
+    use frame_system::{EnsureRoot, RawOrigin};
     use sp_core::H256;
     use sp_io::TestExternalities;
     use sp_runtime::{
@@ -78,9 +689,14 @@ mod tests {
     };
 
     pub fn new_test_ext() -> TestExternalities {
-        let t = frame_system::GenesisConfig::default()
+        let mut t = frame_system::GenesisConfig::default()
             .build_storage::<Test>()
             .unwrap();
+        pallet_balances::GenesisConfig::<Test> {
+            balances: vec![(1, 100), (2, 100)],
+        }
+        .assimilate_storage(&mut t)
+        .unwrap();
         TestExternalities::new(t)
     }
 
@@ -95,6 +711,9 @@ mod tests {
         pub const MaximumBlockWeight: Weight = 1024;
         pub const MaximumBlockLength: u32 = 2 * 1024;
         pub const AvailableBlockRatio: Perbill = Perbill::one();
+        pub const ExistentialDeposit: u64 = 1;
+        pub const MaxLocks: u32 = 50;
+        pub const AppealDeposit: u64 = 10;
     }
     impl frame_system::Trait for Test {
         type BaseCallFilter = ();
@@ -117,17 +736,44 @@ mod tests {
         type AvailableBlockRatio = AvailableBlockRatio;
         type MaximumBlockLength = MaximumBlockLength;
         type Version = ();
-        type ModuleToIndex = ();
-        type AccountData = ();
+        type PalletInfo = ();
+        type AccountData = pallet_balances::AccountData<u64>;
         type OnNewAccount = ();
         type OnKilledAccount = ();
         type SystemWeightInfo = ();
     }
 
+    impl pallet_balances::Trait for Test {
+        type MaxLocks = MaxLocks;
+        type Balance = u64;
+        type Event = ();
+        type DustRemoval = ();
+        type ExistentialDeposit = ExistentialDeposit;
+        type AccountStore = System;
+        type WeightInfo = ();
+    }
+
     impl Trait for Test {
         type Event = ();
+        type Currency = Balances;
+        type CouncilOrigin = EnsureRoot<u64>;
+        type AppealDeposit = AppealDeposit;
+        type WeighReputation = ();
+        type OnBehavior = ();
+        type WeightInfo = ();
     }
 
+    type System = frame_system::Module<Test>;
+    type Balances = pallet_balances::Module<Test>;
+
+    fn context(category: BountyCategory) -> BountyContext<u64> {
+        BountyContext {
+            bounty_id: 0,
+            category,
+            counterparty: 2,
+            value: 0,
+        }
+    }
     type Reputation = Module<Test>;
 
     #[test]
@@ -136,19 +782,497 @@ mod tests {
             let account = 1;
             Reputation::add_behavior_score_by_behavior(
                 &account,
+                Role::Hunter,
                 &Behavior::BountyResolve(BountyResolveCollaborationResult::Success),
+                &context(BountyCategory::Design),
+            );
+            Reputation::add_behavior_score_by_behavior(
+                &account,
+                Role::Funder,
+                &Behavior::BountyRemark(BountyRemarkCollaborationResult::Good),
+                &context(BountyCategory::Development),
             );
 
-            let score = Reputation::behavior_score(&account);
+            let score = Reputation::hunter_score(&account);
             assert_eq!(10, score);
+            assert_eq!(
+                Reputation::reputation_breakdown(&account),
+                ReputationBreakdown {
+                    hunter: 10,
+                    funder: 2,
+                    categories: vec![(BountyCategory::Design, 10)],
+                }
+            );
         });
     }
 
     #[test]
     fn get_behavior_score_works() {
-        let score = Reputation::get_behavior_score(&Behavior::BountyResolve(
-            BountyResolveCollaborationResult::Fail,
-        ));
-        assert_eq!(-2, score);
+        new_test_ext().execute_with(|| {
+            let score = Reputation::get_behavior_score(
+                Role::Hunter,
+                &Behavior::BountyResolve(BountyResolveCollaborationResult::Fail),
+            );
+            assert_eq!(-2, score);
+        });
+    }
+
+    #[test]
+    fn set_score_table_works() {
+        new_test_ext().execute_with(|| {
+            let account = 1;
+            let resolve = Behavior::BountyResolve(BountyResolveCollaborationResult::Success);
+            Reputation::add_behavior_score_by_behavior(
+                &account,
+                Role::Hunter,
+                &resolve,
+                &context(BountyCategory::Design),
+            );
+
+            let table = ScoreTable {
+                resolve_success: 20,
+                ..ScoreTable::HUNTER
+            };
+            assert!(Reputation::set_score_table(
+                RawOrigin::Signed(account).into(),
+                Role::Hunter,
+                table
+            )
+            .is_err());
+            assert!(
+                Reputation::set_score_table(RawOrigin::Root.into(), Role::Hunter, table).is_ok()
+            );
+            assert_eq!(Reputation::hunter_score_table(), table);
+            assert_eq!(Reputation::funder_score_table(), ScoreTable::FUNDER);
+
+            // only the later behaviors use the new table
+            assert_eq!(Reputation::hunter_score(&account), 10);
+            Reputation::add_behavior_score_by_behavior(
+                &account,
+                Role::Hunter,
+                &resolve,
+                &context(BountyCategory::Design),
+            );
+            assert_eq!(Reputation::hunter_score(&account), 30);
+        });
+    }
+
+    #[test]
+    fn scoring_parameters_are_set_by_council() {
+        new_test_ext().execute_with(|| {
+            assert!(Reputation::set_penalties(RawOrigin::Signed(1).into(), -10, -2).is_err());
+            assert_eq!(
+                Reputation::set_penalties(RawOrigin::Root.into(), 1, -2),
+                Err(Error::<Test>::InvalidPenalty.into())
+            );
+            assert!(Reputation::set_penalties(RawOrigin::Root.into(), -10, -2).is_ok());
+            assert_eq!(Reputation::reversal_penalty(), -10);
+            assert_eq!(Reputation::extension_penalty(), -2);
+
+            assert_eq!(
+                Reputation::set_value_weighting(RawOrigin::Root.into(), 0, 4, 20),
+                Err(Error::<Test>::InvalidValueWeighting.into())
+            );
+            assert!(Reputation::set_value_weighting(RawOrigin::Root.into(), 100, 4, 20).is_ok());
+            assert_eq!(Reputation::unit_value(), 100);
+            assert_eq!(Reputation::max_value_multiplier(), 4);
+            assert_eq!(Reputation::low_reputation_threshold(), 20);
+
+            assert_eq!(
+                Reputation::set_endorsement_limits(RawOrigin::Root.into(), 20, 0),
+                Err(Error::<Test>::InvalidEndorsementLimits.into())
+            );
+            assert!(Reputation::set_endorsement_limits(RawOrigin::Root.into(), 20, 3).is_ok());
+            assert_eq!(Reputation::endorsement_unit(), 20);
+            assert_eq!(Reputation::max_endorsement_weight(), 3);
+        });
+    }
+
+    #[test]
+    fn decay_works() {
+        new_test_ext().execute_with(|| {
+            let account = 1;
+            let resolve = Behavior::BountyResolve(BountyResolveCollaborationResult::Success);
+            assert!(Reputation::set_decay_half_life(RawOrigin::Root.into(), Some(0)).is_err());
+            assert!(Reputation::set_decay_half_life(RawOrigin::Root.into(), Some(100)).is_ok());
+
+            System::set_block_number(1);
+            Reputation::add_behavior_score_by_behavior(
+                &account,
+                Role::Hunter,
+                &resolve,
+                &context(BountyCategory::Design),
+            );
+
+            System::set_block_number(101);
+            assert_eq!(
+                Reputation::reputation_score(&account, Role::Hunter),
+                ReputationScore {
+                    raw: 10,
+                    decayed: 5
+                }
+            );
+
+            // 5 * (1 - 50 / 200)
+            System::set_block_number(151);
+            assert_eq!(Reputation::decayed_score(&account, Role::Hunter), 3);
+
+            Reputation::add_behavior_score_by_behavior(
+                &account,
+                Role::Hunter,
+                &resolve,
+                &context(BountyCategory::Design),
+            );
+            assert_eq!(
+                Reputation::reputation_score(&account, Role::Hunter),
+                ReputationScore {
+                    raw: 20,
+                    decayed: 13
+                }
+            );
+
+            assert!(Reputation::set_decay_half_life(RawOrigin::Root.into(), None).is_ok());
+            System::set_block_number(1_000);
+            assert_eq!(Reputation::decayed_score(&account, Role::Hunter), 13);
+        });
+    }
+
+    #[test]
+    fn value_weighted_works() {
+        new_test_ext().execute_with(|| {
+            let (hunter, funder) = (1, 2);
+            let unit = Reputation::unit_value();
+            let weigh = |value| {
+                let context = BountyContext {
+                    bounty_id: 0,
+                    category: BountyCategory::Development,
+                    counterparty: funder,
+                    value,
+                };
+                ValueWeighted::<Test>::weigh(&hunter, Role::Hunter, 10, &context)
+            };
+            FunderScore::<Test>::insert(&funder, 100);
+
+            assert_eq!(weigh(unit / 10), 1);
+            assert_eq!(weigh(unit), 10);
+            // 1 + log2(4)
+            assert_eq!(weigh(unit * 4), 30);
+            assert_eq!(weigh(unit * 1_000_000), 80);
+            assert_eq!(
+                ValueWeighted::<Test>::weigh(
+                    &hunter,
+                    Role::Hunter,
+                    -2,
+                    &context(BountyCategory::Design)
+                ),
+                -2
+            );
+
+            // repeated collaborations
+            Collaborations::<Test>::insert(&hunter, &funder, 1);
+            assert_eq!(weigh(unit), 5);
+
+            // low reputation counterparty
+            FunderScore::<Test>::insert(&funder, 0);
+            assert_eq!(weigh(unit), 2);
+
+            // endorsed counterparty
+            EndorsementScore::<Test>::insert(&funder, BountyCategory::Design, 10);
+            assert_eq!(weigh(unit), 5);
+        });
+    }
+
+    #[test]
+    fn history_works() {
+        new_test_ext().execute_with(|| {
+            let account = 1;
+            MaxHistoryLength::put(2);
+            let behaviors = [
+                Behavior::BountyResolve(BountyResolveCollaborationResult::Success),
+                Behavior::BountyRemark(BountyRemarkCollaborationResult::Good),
+                Behavior::BountyTipped,
+            ];
+            for (i, behavior) in behaviors.iter().enumerate() {
+                System::set_block_number(i as u64 + 1);
+                Reputation::add_behavior_score_by_behavior(
+                    &account,
+                    Role::Hunter,
+                    behavior,
+                    &context(BountyCategory::Design),
+                );
+            }
+
+            // the first entry rolled out
+            assert_eq!(
+                Reputation::history(&account),
+                vec![
+                    HistoryEntry {
+                        role: Role::Hunter,
+                        behavior: Behavior::BountyRemark(BountyRemarkCollaborationResult::Good),
+                        bounty_id: 0,
+                        category: BountyCategory::Design,
+                        counterparty: 2,
+                        score: 3,
+                        block_number: 2,
+                    },
+                    HistoryEntry {
+                        role: Role::Hunter,
+                        behavior: Behavior::BountyTipped,
+                        bounty_id: 0,
+                        category: BountyCategory::Design,
+                        counterparty: 2,
+                        score: 2,
+                        block_number: 3,
+                    },
+                ]
+            );
+            assert_eq!(Reputation::hunter_score(&account), 15);
+        });
+    }
+
+    #[test]
+    fn appeal_works() {
+        new_test_ext().execute_with(|| {
+            let (hunter, funder) = (1, 2);
+            let bad = Behavior::BountyRemark(BountyRemarkCollaborationResult::Bad);
+            let appeal = |bounty_id| {
+                Reputation::appeal_remark(
+                    RawOrigin::Signed(hunter).into(),
+                    bounty_id,
+                    Default::default(),
+                )
+            };
+            System::set_block_number(1);
+            Reputation::add_behavior_score_by_behavior(
+                &hunter,
+                Role::Hunter,
+                &Behavior::BountyRemark(BountyRemarkCollaborationResult::Good),
+                &context(BountyCategory::Design),
+            );
+            Reputation::add_behavior_score_by_behavior(
+                &hunter,
+                Role::Hunter,
+                &bad,
+                &BountyContext {
+                    bounty_id: 1,
+                    ..context(BountyCategory::Design)
+                },
+            );
+            assert_eq!(Reputation::hunter_score(&hunter), 1);
+
+            assert_eq!(appeal(0), Err(Error::<Test>::RemarkNotFound.into()));
+            assert_eq!(appeal(2), Err(Error::<Test>::RemarkNotFound.into()));
+            assert!(appeal(1).is_ok());
+            assert_eq!(appeal(1), Err(Error::<Test>::AlreadyAppealed.into()));
+            assert_eq!(Balances::reserved_balance(&hunter), 10);
+
+            assert!(
+                Reputation::resolve_appeal(RawOrigin::Signed(funder).into(), hunter, 1, true)
+                    .is_err()
+            );
+            assert!(Reputation::resolve_appeal(RawOrigin::Root.into(), hunter, 1, true).is_ok());
+            assert_eq!(
+                Reputation::resolve_appeal(RawOrigin::Root.into(), hunter, 1, false),
+                Err(Error::<Test>::AppealNotFound.into())
+            );
+
+            // the remark is undone and the remarker is penalized
+            assert_eq!(Reputation::hunter_score(&hunter), 3);
+            assert_eq!(
+                Reputation::category_score(&hunter, BountyCategory::Design),
+                3
+            );
+            assert_eq!(Reputation::funder_score(&funder), -5);
+            assert_eq!(Balances::reserved_balance(&hunter), 0);
+            assert_eq!(Balances::free_balance(&hunter), 100);
+            // the resolved appeal is removed, and the remark could not be appealed again
+            assert!(Reputation::appeals(&hunter, 1).is_none());
+            assert_eq!(appeal(1), Err(Error::<Test>::RemarkNotFound.into()));
+
+            // an upheld remark slashes the deposit
+            Reputation::add_behavior_score_by_behavior(
+                &hunter,
+                Role::Hunter,
+                &bad,
+                &BountyContext {
+                    bounty_id: 2,
+                    ..context(BountyCategory::Design)
+                },
+            );
+            assert!(appeal(2).is_ok());
+            assert!(Reputation::resolve_appeal(RawOrigin::Root.into(), hunter, 2, false).is_ok());
+            assert_eq!(Reputation::hunter_score(&hunter), 1);
+            assert_eq!(Balances::free_balance(&hunter), 90);
+
+            // too late to appeal
+            Reputation::add_behavior_score_by_behavior(
+                &hunter,
+                Role::Hunter,
+                &bad,
+                &BountyContext {
+                    bounty_id: 3,
+                    ..context(BountyCategory::Design)
+                },
+            );
+            System::set_block_number(1 + Reputation::appeal_period());
+            assert_eq!(appeal(3), Err(Error::<Test>::AppealPeriodOver.into()));
+
+            // the expired remark is dropped
+            Reputation::on_initialize(1 + Reputation::appeal_period());
+            assert!(Reputation::appealable_remarks(&hunter, 3).is_none());
+            assert_eq!(appeal(3), Err(Error::<Test>::RemarkNotFound.into()));
+        });
+    }
+
+    #[test]
+    fn remark_is_appealable_after_leaving_history() {
+        new_test_ext().execute_with(|| {
+            let hunter = 1;
+            MaxHistoryLength::put(2);
+            System::set_block_number(1);
+            Reputation::add_behavior_score_by_behavior(
+                &hunter,
+                Role::Hunter,
+                &Behavior::BountyRemark(BountyRemarkCollaborationResult::Bad),
+                &context(BountyCategory::Design),
+            );
+            for _ in 0..3 {
+                Reputation::add_behavior_score_by_behavior(
+                    &hunter,
+                    Role::Hunter,
+                    &Behavior::BountyTipped,
+                    &BountyContext {
+                        bounty_id: 1,
+                        ..context(BountyCategory::Design)
+                    },
+                );
+            }
+            assert!(Reputation::history(&hunter)
+                .iter()
+                .all(|entry| entry.behavior == Behavior::BountyTipped));
+
+            assert!(Reputation::appeal_remark(
+                RawOrigin::Signed(hunter).into(),
+                0,
+                Default::default()
+            )
+            .is_ok());
+            assert_eq!(
+                Reputation::appeals(&hunter, 0).map(|appeal| appeal.entry.score),
+                Some(-2)
+            );
+            assert!(Reputation::appealable_remarks(&hunter, 0).is_none());
+        });
+    }
+
+    #[test]
+    fn endorsement_works() {
+        new_test_ext().execute_with(|| {
+            let (endorser, newcomer) = (1, 2);
+            let endorse =
+                |skill| Reputation::endorse(RawOrigin::Signed(endorser).into(), newcomer, skill);
+            assert_eq!(
+                endorse(BountyCategory::Development),
+                Err(Error::<Test>::EndorserReputationTooLow.into())
+            );
+
+            HunterScore::<Test>::insert(&endorser, 35);
+            assert_eq!(
+                Reputation::endorse(
+                    RawOrigin::Signed(endorser).into(),
+                    endorser,
+                    BountyCategory::Development
+                ),
+                Err(Error::<Test>::SelfEndorsement.into())
+            );
+            assert!(endorse(BountyCategory::Development).is_ok());
+            assert_eq!(
+                endorse(BountyCategory::Development),
+                Err(Error::<Test>::AlreadyEndorsed.into())
+            );
+
+            // capped by the max weight over all skills of the account
+            FunderScore::<Test>::insert(&endorser, 100);
+            assert!(endorse(BountyCategory::Design).is_ok());
+            assert_eq!(
+                Reputation::endorsement_score(&newcomer, BountyCategory::Development),
+                3
+            );
+            assert_eq!(
+                Reputation::endorsement_score(&newcomer, BountyCategory::Design),
+                2
+            );
+            assert_eq!(Reputation::endorsed_weight(&newcomer, &endorser), 5);
+            assert_eq!(
+                endorse(BountyCategory::Document),
+                Err(Error::<Test>::EndorsementLimitReached.into())
+            );
+            assert_eq!(Reputation::hunter_score(&newcomer), 0);
+
+            assert!(Reputation::revoke_endorsement(
+                RawOrigin::Signed(endorser).into(),
+                newcomer,
+                BountyCategory::Development
+            )
+            .is_ok());
+            assert_eq!(
+                Reputation::revoke_endorsement(
+                    RawOrigin::Signed(endorser).into(),
+                    newcomer,
+                    BountyCategory::Development
+                ),
+                Err(Error::<Test>::EndorsementNotFound.into())
+            );
+            assert_eq!(
+                Reputation::endorsement_score(&newcomer, BountyCategory::Development),
+                0
+            );
+            assert_eq!(
+                Reputation::endorsement_score(&newcomer, BountyCategory::Design),
+                2
+            );
+
+            // the revoked weight could be given again
+            assert!(endorse(BountyCategory::Document).is_ok());
+            assert_eq!(
+                Reputation::endorsement_score(&newcomer, BountyCategory::Document),
+                3
+            );
+            assert_eq!(Reputation::endorsed_weight(&newcomer, &endorser), 5);
+        });
+    }
+
+    #[test]
+    fn genesis_sets_latest_storage_version() {
+        let mut t = frame_system::GenesisConfig::default()
+            .build_storage::<Test>()
+            .unwrap();
+        GenesisConfig::<Test>::default()
+            .assimilate_storage(&mut t)
+            .unwrap();
+        TestExternalities::new(t).execute_with(|| {
+            assert_eq!(Reputation::storage_version(), Releases::V2_0_0);
+            assert_eq!(Reputation::on_runtime_upgrade(), 0);
+        });
+    }
+
+    #[test]
+    fn migrate_behavior_score_to_hunter_track_works() {
+        new_test_ext().execute_with(|| {
+            let account: u64 = 1;
+            let hashed_key = frame_support::Blake2_128Concat::hash(&account.encode());
+            put_storage_value(b"OsReputation", b"BehaviorScore", &hashed_key, 7i128);
+
+            Reputation::on_runtime_upgrade();
+
+            assert_eq!(Reputation::hunter_score(&account), 7);
+            assert_eq!(Reputation::funder_score(&account), 0);
+            assert_eq!(Reputation::storage_version(), Releases::V2_0_0);
+            assert!(
+                StorageIterator::<i128>::new(b"OsReputation", b"BehaviorScore")
+                    .next()
+                    .is_none()
+            );
+        });
     }
 }
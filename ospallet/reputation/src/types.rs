@@ -2,8 +2,13 @@ use codec::{Decode, Encode};
 #[cfg(feature = "std")]
 use serde::{Deserialize, Serialize};
 
+use frame_support::weights::Weight;
 use sp_runtime::RuntimeDebug;
+use sp_std::prelude::*;
 
+use opensquare_primitives::{BountyCategory, BountyId, SdDigest};
+
+#[derive(PartialEq, Eq, Copy, Clone, Encode, Decode, RuntimeDebug)]
 #[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
 pub enum BountyResolveCollaborationResult {
     Success,
@@ -20,15 +25,229 @@ pub enum BountyRemarkCollaborationResult {
     Perfect,
 }
 
+/// Role of an account in a collaboration, each role has its own reputation track.
+#[derive(PartialEq, Eq, Copy, Clone, Encode, Decode, RuntimeDebug)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+pub enum Role {
+    Funder,
+    Hunter,
+}
+
 // Behavior represent the general collaboration result
+#[derive(PartialEq, Eq, Copy, Clone, Encode, Decode, RuntimeDebug)]
 #[cfg_attr(feature = "std", derive(Serialize, Deserialize), serde(untagged))]
 pub enum Behavior {
     BountyResolve(BountyResolveCollaborationResult),
     BountyRemark(BountyRemarkCollaborationResult),
+    // Funder paid an extra tip for the work
+    BountyTipped,
+    // A remark was reversed on appeal, the remarker is penalized and the remarked gets the score back.
+    // The named field keeps it distinct from other behaviors in untagged json.
+    RemarkReversed {
+        remark: BountyRemarkCollaborationResult,
+    },
+    // The hunter had more approved extensions of a bounty than the free ones
+    ExtensionOverused {
+        extensions: u32,
+    },
+}
+
+/// Score of each behavior, every role has its own table.
+#[derive(PartialEq, Eq, Copy, Clone, Default, Encode, Decode, RuntimeDebug)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+pub struct ScoreTable {
+    pub resolve_success: i128,
+    pub resolve_fail: i128,
+    pub remark_bad: i128,
+    pub remark_not_good: i128,
+    pub remark_fine: i128,
+    pub remark_good: i128,
+    pub remark_perfect: i128,
+    pub tipped: i128,
+}
+
+impl ScoreTable {
+    pub const HUNTER: ScoreTable = ScoreTable {
+        resolve_success: 10,
+        resolve_fail: -2,
+        remark_bad: -2,
+        remark_not_good: 0,
+        remark_fine: 1,
+        remark_good: 3,
+        remark_perfect: 5,
+        tipped: 2,
+    };
+
+    pub const FUNDER: ScoreTable = ScoreTable {
+        resolve_success: 5,
+        resolve_fail: -3,
+        remark_bad: -3,
+        remark_not_good: -1,
+        remark_fine: 1,
+        remark_good: 2,
+        remark_perfect: 3,
+        tipped: 1,
+    };
+
+    pub fn score_of(&self, behavior: &Behavior) -> i128 {
+        match behavior {
+            Behavior::BountyResolve(BountyResolveCollaborationResult::Success) => {
+                self.resolve_success
+            }
+            Behavior::BountyResolve(BountyResolveCollaborationResult::Fail) => self.resolve_fail,
+            Behavior::BountyRemark(BountyRemarkCollaborationResult::Bad) => self.remark_bad,
+            Behavior::BountyRemark(BountyRemarkCollaborationResult::NotGood) => {
+                self.remark_not_good
+            }
+            Behavior::BountyRemark(BountyRemarkCollaborationResult::Fine) => self.remark_fine,
+            Behavior::BountyRemark(BountyRemarkCollaborationResult::Good) => self.remark_good,
+            Behavior::BountyRemark(BountyRemarkCollaborationResult::Perfect) => self.remark_perfect,
+            Behavior::BountyTipped => self.tipped,
+            // scored by `ReversalPenalty` out of the tables
+            Behavior::RemarkReversed { .. } => 0,
+            // scored by `ExtensionPenalty` out of the tables
+            Behavior::ExtensionOverused { .. } => 0,
+        }
+    }
+}
+
+/// Reputation of an account as the hunter and the funder, and the hunter score gained in each bounty category.
+#[derive(PartialEq, Eq, Clone, Default, Encode, Decode, RuntimeDebug)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+pub struct ReputationBreakdown {
+    pub hunter: i128,
+    pub funder: i128,
+    pub categories: Vec<(BountyCategory, i128)>,
+}
+
+/// A reputation change of an account and its cause.
+#[derive(PartialEq, Eq, Clone, Encode, Decode, RuntimeDebug)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+pub struct HistoryEntry<AccountId, BlockNumber> {
+    pub role: Role,
+    pub behavior: Behavior,
+    pub bounty_id: BountyId,
+    pub category: BountyCategory,
+    pub counterparty: AccountId,
+    /// Weighted score added by the behavior
+    pub score: i128,
+    pub block_number: BlockNumber,
+}
+
+#[derive(PartialEq, Eq, Copy, Clone, Encode, Decode, RuntimeDebug)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+pub enum AppealStatus {
+    Upheld,
+    Reversed,
+}
+
+/// Pending appeal of the rated party against a remark, the appealed remark is kept in `entry`.
+#[derive(PartialEq, Eq, Clone, Encode, Decode, RuntimeDebug)]
+pub struct Appeal<AccountId, Balance, BlockNumber> {
+    pub entry: HistoryEntry<AccountId, BlockNumber>,
+    /// Digest of the evidence kept off chain
+    pub evidence: SdDigest,
+    pub deposit: Balance,
+}
+
+/// Decayed score of a role, decayed to `updated_at` and decays lazily from then on.
+#[derive(PartialEq, Eq, Copy, Clone, Default, Encode, Decode, RuntimeDebug)]
+pub struct DecayedScore<BlockNumber> {
+    pub score: i128,
+    pub updated_at: BlockNumber,
+}
+
+/// Score of a role, `raw` is the sum of all behavior scores and `decayed` is decayed by their ages.
+#[derive(PartialEq, Eq, Copy, Clone, Default, Encode, Decode, RuntimeDebug)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+pub struct ReputationScore {
+    pub raw: i128,
+    pub decayed: i128,
+}
+
+#[derive(PartialEq, Eq, Copy, Clone, Encode, Decode, RuntimeDebug)]
+pub enum Releases {
+    /// single `BehaviorScore` for all roles
+    V1_0_0,
+    /// `HunterScore` and `FunderScore`
+    V2_0_0,
+}
+
+impl Default for Releases {
+    fn default() -> Self {
+        Releases::V1_0_0
+    }
+}
+
+/// The bounty which brings a behavior.
+#[derive(PartialEq, Eq, Clone, Encode, Decode, RuntimeDebug)]
+pub struct BountyContext<AccountId> {
+    pub bounty_id: BountyId,
+    pub category: BountyCategory,
+    /// The other side of the collaboration
+    pub counterparty: AccountId,
+    /// Payment of the bounty normalized by the currency ratio
+    pub value: u128,
+}
+
+/// Weigh the score of a behavior by the bounty which brings it.
+pub trait WeighReputation<AccountId> {
+    fn weigh(
+        target: &AccountId,
+        role: Role,
+        score: i128,
+        context: &BountyContext<AccountId>,
+    ) -> i128;
+}
+
+impl<AccountId> WeighReputation<AccountId> for () {
+    fn weigh(
+        _target: &AccountId,
+        _role: Role,
+        score: i128,
+        _context: &BountyContext<AccountId>,
+    ) -> i128 {
+        score
+    }
+}
+
+/// Handle a behavior after its score is added to an account.
+pub trait OnBehavior<AccountId> {
+    fn on_behavior(
+        target: &AccountId,
+        role: Role,
+        behavior: &Behavior,
+        context: &BountyContext<AccountId>,
+    );
+
+    /// Weight of `on_behavior`, it's charged by the calls adding behaviors.
+    fn on_behavior_weight() -> Weight {
+        0
+    }
+}
+
+impl<AccountId> OnBehavior<AccountId> for () {
+    fn on_behavior(
+        _target: &AccountId,
+        _role: Role,
+        _behavior: &Behavior,
+        _context: &BountyContext<AccountId>,
+    ) {
+    }
 }
 
 pub trait ReputationBuilder<AccountId> {
-    fn add_behavior_score_by_behavior(target: &AccountId, behavior: &Behavior);
+    /// Add the weighted behavior score to the track of the role, the hunter score is also added to the bounty category.
+    fn add_behavior_score_by_behavior(
+        target: &AccountId,
+        role: Role,
+        behavior: &Behavior,
+        context: &BountyContext<AccountId>,
+    );
+
+    fn get_behavior_score(role: Role, behavior: &Behavior) -> i128;
 
-    fn get_behavior_score(behavior: &Behavior) -> i128;
+    /// Weight of the hooks run when a behavior is added, callers should add it for each behavior
+    /// besides the weight of the reputation storage.
+    fn behavior_hooks_weight() -> Weight;
 }
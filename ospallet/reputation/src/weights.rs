@@ -0,0 +1,58 @@
+//! Weights for ospallet_reputation.
+//!
+//! `()` implements `WeightInfo` with the weights used in tests and chains without their own
+//! benchmark results. Runtimes should use the weights generated by the `benchmark` subcommand.
+
+#![allow(unused_parens)]
+
+use frame_support::weights::{constants::RocksDbWeight as DbWeight, Weight};
+
+pub trait WeightInfo {
+    fn set_score_table() -> Weight;
+    fn set_decay_half_life() -> Weight;
+    fn appeal_remark() -> Weight;
+    fn resolve_appeal() -> Weight;
+    fn endorse() -> Weight;
+    fn revoke_endorsement() -> Weight;
+    fn set_penalties() -> Weight;
+    fn set_value_weighting() -> Weight;
+    fn set_endorsement_limits() -> Weight;
+}
+
+impl WeightInfo for () {
+    fn set_score_table() -> Weight {
+        (25_000_000 as Weight).saturating_add(DbWeight::get().writes(1 as Weight))
+    }
+    fn set_decay_half_life() -> Weight {
+        (25_000_000 as Weight).saturating_add(DbWeight::get().writes(1 as Weight))
+    }
+    fn appeal_remark() -> Weight {
+        (50_000_000 as Weight)
+            .saturating_add(DbWeight::get().reads(4 as Weight))
+            .saturating_add(DbWeight::get().writes(3 as Weight))
+    }
+    fn resolve_appeal() -> Weight {
+        (90_000_000 as Weight)
+            .saturating_add(DbWeight::get().reads(14 as Weight))
+            .saturating_add(DbWeight::get().writes(14 as Weight))
+    }
+    fn endorse() -> Weight {
+        (55_000_000 as Weight)
+            .saturating_add(DbWeight::get().reads(9 as Weight))
+            .saturating_add(DbWeight::get().writes(3 as Weight))
+    }
+    fn revoke_endorsement() -> Weight {
+        (45_000_000 as Weight)
+            .saturating_add(DbWeight::get().reads(3 as Weight))
+            .saturating_add(DbWeight::get().writes(3 as Weight))
+    }
+    fn set_penalties() -> Weight {
+        (25_000_000 as Weight).saturating_add(DbWeight::get().writes(2 as Weight))
+    }
+    fn set_value_weighting() -> Weight {
+        (25_000_000 as Weight).saturating_add(DbWeight::get().writes(3 as Weight))
+    }
+    fn set_endorsement_limits() -> Weight {
+        (25_000_000 as Weight).saturating_add(DbWeight::get().writes(2 as Weight))
+    }
+}
@@ -0,0 +1,28 @@
+//! Benchmarks for ospallet_system.
+
+#![cfg(feature = "runtime-benchmarks")]
+
+use frame_benchmarking::{account, benchmarks};
+use frame_system::RawOrigin;
+use sp_std::{prelude::*, vec};
+
+use crate::{Module, Trait};
+
+benchmarks! {
+    _ { }
+
+    modify_paused {
+        let pallet = b"OsBounties".to_vec();
+        let call = Some(vec![b'x'; 32]);
+    }: _(RawOrigin::Root, pallet.clone(), call, true)
+    verify {
+        assert!(!Module::<T>::paused(&pallet).is_empty());
+    }
+
+    modify_blocked_list {
+        let who: T::AccountId = account("blocked", 0, 0);
+    }: _(RawOrigin::Root, who.clone(), true)
+    verify {
+        assert!(Module::<T>::blocked_accounts(&who));
+    }
+}
@@ -9,9 +9,18 @@ use frame_support::{
 };
 use frame_system::{self as system, ensure_root};
 
+#[cfg(feature = "runtime-benchmarks")]
+mod benchmarking;
+mod weights;
+
+pub use weights::WeightInfo;
+
 pub trait Trait: system::Trait {
     /// Event
     type Event: From<Event<Self>> + Into<<Self as system::Trait>::Event>;
+
+    /// Weight information for extrinsics in this pallet.
+    type WeightInfo: WeightInfo;
 }
 
 decl_error! {
@@ -34,7 +43,7 @@ decl_module! {
     pub struct Module<T: Trait> for enum Call where origin: T::Origin {
         fn deposit_event() = default;
 
-        #[weight = 0]
+        #[weight = T::WeightInfo::modify_paused()]
         pub fn modify_paused(origin, pallet: Vec<u8>, call: Option<Vec<u8>>, paused: bool) -> DispatchResult {
             ensure_root(origin)?;
             let mut sub_paused = Self::paused(&pallet);
@@ -61,7 +70,7 @@ decl_module! {
             Ok(())
         }
 
-        #[weight = 0]
+        #[weight = T::WeightInfo::modify_blocked_list()]
         fn modify_blocked_list(origin, who: T::AccountId, block: bool) -> DispatchResult {
             ensure_root(origin)?;
             if block {
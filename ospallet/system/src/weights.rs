@@ -0,0 +1,24 @@
+//! Weights for ospallet_system.
+//!
+//! `()` implements `WeightInfo` with the weights used in tests and chains without their own
+//! benchmark results. Runtimes should use the weights generated by the `benchmark` subcommand.
+
+#![allow(unused_parens)]
+
+use frame_support::weights::{constants::RocksDbWeight as DbWeight, Weight};
+
+pub trait WeightInfo {
+    fn modify_paused() -> Weight;
+    fn modify_blocked_list() -> Weight;
+}
+
+impl WeightInfo for () {
+    fn modify_paused() -> Weight {
+        (25_000_000 as Weight)
+            .saturating_add(DbWeight::get().reads(1 as Weight))
+            .saturating_add(DbWeight::get().writes(1 as Weight))
+    }
+    fn modify_blocked_list() -> Weight {
+        (20_000_000 as Weight).saturating_add(DbWeight::get().writes(1 as Weight))
+    }
+}
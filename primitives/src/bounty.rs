@@ -0,0 +1,14 @@
+use codec::{Decode, Encode};
+#[cfg(feature = "std")]
+use serde::{Deserialize, Serialize};
+
+use sp_runtime::RuntimeDebug;
+
+/// Category of a bounty, reputation is also tracked per category.
+#[derive(PartialEq, Eq, Clone, Copy, Encode, Decode, RuntimeDebug)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+pub enum BountyCategory {
+    Development,
+    Design,
+    Document,
+}
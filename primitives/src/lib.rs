@@ -1,5 +1,6 @@
 #![cfg_attr(not(feature = "std"), no_std)]
 
+pub mod bounty;
 pub mod currency;
 
 use sp_runtime::{
@@ -8,6 +9,7 @@ use sp_runtime::{
     FixedU128, MultiSignature, OpaqueExtrinsic,
 };
 
+pub use crate::bounty::*;
 pub use crate::currency::*;
 
 /// An index to a block.
@@ -63,6 +65,9 @@ pub type SdDigest = sp_core::H256;
 /// unique index for a bounty
 pub type BountyId = sp_core::H256;
 
+/// sequential index for a bounty, in the order of creation
+pub type BountyIndex = u32;
+
 pub const BLOCKS_PER_MINUTE: u32 = 10;
 pub const BLOCKS_PER_HOUR: u32 = BLOCKS_PER_MINUTE * 60;
 pub const BLOCKS_PER_DAY: u32 = BLOCKS_PER_HOUR * 24;
@@ -48,9 +48,10 @@ pub use pallet_balances::Call as BalancesCall;
 pub use pallet_timestamp::Call as TimestampCall;
 
 pub use opensquare_primitives::{
-    AccountId, AccountIndex, Amount, Balance, BlockNumber, CurrencyId, Hash, Index, Moment, Price,
-    Signature,
+    AccountId, AccountIndex, Amount, Balance, BlockNumber, BountyId, CurrencyId, Hash, Index,
+    Moment, Price, Signature,
 };
+pub use ospallet_bounties::{BountyCategory, BountyState};
 
 pub mod constants;
 pub mod weights;
@@ -69,10 +70,10 @@ pub const VERSION: RuntimeVersion = RuntimeVersion {
     spec_name: create_runtime_str!("opensquare"),
     impl_name: create_runtime_str!("opensquare"),
     authoring_version: 1,
-    spec_version: 1,
+    spec_version: 2,
     impl_version: 1,
     apis: RUNTIME_API_VERSIONS,
-    transaction_version: 1,
+    transaction_version: 2,
 };
 
 /// The version information used to identify this runtime when compiled natively.
@@ -278,11 +279,15 @@ type EnsureRootOrCouncil =
 
 impl ospallet_system::Trait for Runtime {
     type Event = Event;
+    type WeightInfo = weights::ospallet_system::WeightInfo;
 }
 
 parameter_types! {
     pub const CouncilFee: Percent = Percent::from_percent(5);
     pub CouncilAccount: AccountId = council(); // TODO tmp use a council function
+    pub const ReapDeposit: Balance = 1 * DOLLARS;
+    pub const TipMining: bool = true;
+    pub const TipReputation: bool = true;
 }
 
 fn council() -> AccountId {
@@ -295,19 +300,45 @@ impl ospallet_bounties::Trait for Runtime {
     type CouncilOrigin = EnsureRootOrCouncil;
     type CouncilAccount = CouncilAccount;
     type CouncilFee = CouncilFee;
-    type DetermineBountyId = ospallet_bounties::SimpleBountyIdDeterminer<Runtime>;
-    type BountyResolved = ();
+    type DetermineBountyId = ospallet_bounties::SequentialBountyIdDeterminer<Runtime>;
+    type BountyResolved = (OsProfiles, OsBadges);
     type ReputationBuilder = OsReputation;
     type MiningPowerBuilder = OsMining;
+    type GetNativeCurrencyId = GetNativeCurrencyId;
+    type ReapDeposit = ReapDeposit;
+    type TipMining = TipMining;
+    type TipReputation = TipReputation;
+    type WeightInfo = weights::ospallet_bounties::WeightInfo;
+}
+
+parameter_types! {
+    pub const AppealDeposit: Balance = 1 * DOLLARS;
 }
 
 impl ospallet_reputation::Trait for Runtime {
     type Event = Event;
+    type Currency = Balances;
+    type CouncilOrigin = EnsureRootOrCouncil;
+    type AppealDeposit = AppealDeposit;
+    type WeighReputation = ospallet_reputation::ValueWeighted<Runtime>;
+    type OnBehavior = OsBadges;
+    type WeightInfo = weights::ospallet_reputation::WeightInfo;
 }
 
 impl ospallet_mining::Trait for Runtime {
     type Event = Event;
     type Currency = Balances;
+    type WeightInfo = weights::ospallet_mining::WeightInfo;
+}
+
+impl ospallet_profiles::Trait for Runtime {
+    type Event = Event;
+    type WeightInfo = weights::ospallet_profiles::WeightInfo;
+}
+
+impl ospallet_badges::Trait for Runtime {
+    type Event = Event;
+    type WeightInfo = weights::ospallet_badges::WeightInfo;
 }
 
 // Create the runtime by composing the FRAME pallets that were previously configured.
@@ -333,8 +364,10 @@ construct_runtime!(
 
         OsSystem: ospallet_system::{Module, Call, Config<T>, Storage, Event<T>},
         OsBounties: ospallet_bounties::{Module, Call, Storage, Event<T>, Config<T>},
-        OsReputation: ospallet_reputation::{Module, Storage, Event<T>},
+        OsReputation: ospallet_reputation::{Module, Call, Storage, Event<T>, Config<T>},
         OsMining: ospallet_mining::{Module, Call, Storage, Event<T>},
+        OsProfiles: ospallet_profiles::{Module, Call, Storage, Event<T>},
+        OsBadges: ospallet_badges::{Module, Call, Storage, Event<T>, Config},
     }
 );
 
@@ -494,4 +527,91 @@ impl_runtime_apis! {
             TransactionPayment::query_info(uxt, len)
         }
     }
+
+    impl ospallet_bounties_rpc_runtime_api::BountiesApi<Block> for Runtime {
+        fn bounties_by_state(state: BountyState, offset: u32, limit: u32) -> Vec<BountyId> {
+            OsBounties::bounties_in_state(state, offset, limit)
+        }
+
+        fn bounties_by_category(category: BountyCategory, offset: u32, limit: u32) -> Vec<BountyId> {
+            OsBounties::bounties_in_category(category, offset, limit)
+        }
+    }
+
+    impl ospallet_reputation_rpc_runtime_api::ReputationApi<Block, AccountId, BlockNumber> for Runtime {
+        fn reputation_breakdown(who: AccountId) -> ospallet_reputation::ReputationBreakdown {
+            OsReputation::reputation_breakdown(&who)
+        }
+
+        fn reputation_score(who: AccountId, role: ospallet_reputation::Role) -> ospallet_reputation::ReputationScore {
+            OsReputation::reputation_score(&who, role)
+        }
+
+        fn score_table(role: ospallet_reputation::Role) -> ospallet_reputation::ScoreTable {
+            OsReputation::score_table(role)
+        }
+
+        fn reputation_history(who: AccountId) -> Vec<ospallet_reputation::HistoryEntry<AccountId, BlockNumber>> {
+            OsReputation::history(who)
+        }
+
+        fn endorsement_scores(who: AccountId) -> Vec<(BountyCategory, i128)> {
+            OsReputation::endorsement_scores(&who)
+        }
+    }
+
+    impl ospallet_profiles_rpc_runtime_api::ProfilesApi<Block, AccountId> for Runtime {
+        fn profile_of(who: AccountId) -> Option<ospallet_profiles::Profile> {
+            OsProfiles::profiles(who)
+        }
+
+        fn accounts_with_skill(category: BountyCategory, verified_only: bool, offset: u32, limit: u32) -> Vec<AccountId> {
+            OsProfiles::accounts_with_skill(category, verified_only, offset, limit)
+        }
+    }
+
+    impl ospallet_badges_rpc_runtime_api::BadgesApi<Block, AccountId, BlockNumber> for Runtime {
+        fn badges_of(who: AccountId) -> Vec<(ospallet_badges::BadgeId, BlockNumber)> {
+            OsBadges::badges_of(&who)
+        }
+
+        fn badge_rules() -> Vec<(ospallet_badges::BadgeId, ospallet_badges::BadgeRule)> {
+            OsBadges::rules()
+        }
+    }
+
+    #[cfg(feature = "runtime-benchmarks")]
+    impl frame_benchmarking::Benchmark<Block> for Runtime {
+        fn dispatch_benchmark(
+            config: frame_benchmarking::BenchmarkConfig
+        ) -> Result<Vec<frame_benchmarking::BenchmarkBatch>, sp_runtime::RuntimeString> {
+            use frame_benchmarking::{add_benchmark, BenchmarkBatch, Benchmarking};
+
+            let whitelist: Vec<Vec<u8>> = sp_std::vec![
+                // Block Number
+                hex_literal::hex!("26aa394eea5630e07c48ae0c9558cef702a5c1b21f0dfb8d0bd90f7b0f1c1a2b").to_vec(),
+                // Total Issuance
+                hex_literal::hex!("c2261276cc9d1f8598ea4b6a74b15c2f57c875e4cff74148e4628f264b974c80").to_vec(),
+                // Execution Phase
+                hex_literal::hex!("26aa394eea5630e07c48ae0c9558cef7ff553b5a9862a516939d82b3d3d8661a").to_vec(),
+                // Event Count
+                hex_literal::hex!("26aa394eea5630e07c48ae0c9558cef70a98fdbe9ce6c55837576c60c7af3850").to_vec(),
+                // System Events
+                hex_literal::hex!("26aa394eea5630e07c48ae0c9558cef780d41e5e16056765bc8461851072c9d7").to_vec(),
+            ];
+
+            let mut batches = Vec::<BenchmarkBatch>::new();
+            let params = (&config, &whitelist);
+
+            add_benchmark!(params, batches, ospallet_system, OsSystem);
+            add_benchmark!(params, batches, ospallet_bounties, OsBounties);
+            add_benchmark!(params, batches, ospallet_reputation, OsReputation);
+            add_benchmark!(params, batches, ospallet_mining, OsMining);
+            add_benchmark!(params, batches, ospallet_profiles, OsProfiles);
+            add_benchmark!(params, batches, ospallet_badges, OsBadges);
+
+            if batches.is_empty() { return Err("Benchmark not found for this pallet.".into()) }
+            Ok(batches)
+        }
+    }
 }
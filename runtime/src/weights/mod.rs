@@ -1,3 +1,9 @@
 pub mod frame_system;
 pub mod pallet_balances;
 pub mod pallet_timestamp;
+pub mod ospallet_badges;
+pub mod ospallet_bounties;
+pub mod ospallet_mining;
+pub mod ospallet_profiles;
+pub mod ospallet_reputation;
+pub mod ospallet_system;
@@ -0,0 +1,40 @@
+//! Weights for ospallet_badges.
+//!
+//! These are estimated from the storage access of each call and have not been benchmarked yet,
+//! they must be replaced by the output of `scripts/benchmark.sh` on the reference hardware before
+//! a release.
+
+#![allow(unused_parens)]
+
+use frame_support::weights::{constants::RocksDbWeight as DbWeight, Weight};
+
+pub struct WeightInfo;
+impl ospallet_badges::WeightInfo for WeightInfo {
+    fn add_badge_rule(r: u32) -> Weight {
+        (30_000_000 as Weight)
+            .saturating_add((300_000 as Weight).saturating_mul(r as Weight))
+            .saturating_add(DbWeight::get().reads(4 as Weight))
+            .saturating_add(DbWeight::get().writes(2 as Weight))
+    }
+    fn remove_badge_rule(r: u32) -> Weight {
+        (30_000_000 as Weight)
+            .saturating_add((300_000 as Weight).saturating_mul(r as Weight))
+            .saturating_add(DbWeight::get().reads(1 as Weight))
+            .saturating_add(DbWeight::get().writes(1 as Weight))
+    }
+    fn note_resolved(r: u32) -> Weight {
+        (15_000_000 as Weight)
+            .saturating_add((4_000_000 as Weight).saturating_mul(r as Weight))
+            .saturating_add(DbWeight::get().reads(1 as Weight))
+            .saturating_add(DbWeight::get().reads((4 as Weight).saturating_mul(r as Weight)))
+            .saturating_add(DbWeight::get().writes((1 as Weight).saturating_mul(r as Weight)))
+    }
+    fn on_behavior(r: u32) -> Weight {
+        (15_000_000 as Weight)
+            .saturating_add((3_000_000 as Weight).saturating_mul(r as Weight))
+            .saturating_add(DbWeight::get().reads(2 as Weight))
+            .saturating_add(DbWeight::get().reads((2 as Weight).saturating_mul(r as Weight)))
+            .saturating_add(DbWeight::get().writes(1 as Weight))
+            .saturating_add(DbWeight::get().writes((1 as Weight).saturating_mul(r as Weight)))
+    }
+}
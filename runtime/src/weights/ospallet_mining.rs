@@ -0,0 +1,18 @@
+//! Weights for ospallet_mining.
+//!
+//! These are estimated from the storage access of each call and have not been benchmarked yet,
+//! they must be replaced by the output of `scripts/benchmark.sh` on the reference hardware before
+//! a release.
+
+#![allow(unused_parens)]
+
+use frame_support::weights::{constants::RocksDbWeight as DbWeight, Weight};
+
+pub struct WeightInfo;
+impl ospallet_mining::WeightInfo for WeightInfo {
+    fn claim() -> Weight {
+        (70_000_000 as Weight)
+            .saturating_add(DbWeight::get().reads(5 as Weight))
+            .saturating_add(DbWeight::get().writes(3 as Weight))
+    }
+}
@@ -0,0 +1,31 @@
+//! Weights for ospallet_profiles.
+//!
+//! These are estimated from the storage access of each call and have not been benchmarked yet,
+//! they must be replaced by the output of `scripts/benchmark.sh` on the reference hardware before
+//! a release.
+
+#![allow(unused_parens)]
+
+use frame_support::weights::{constants::RocksDbWeight as DbWeight, Weight};
+
+pub struct WeightInfo;
+impl ospallet_profiles::WeightInfo for WeightInfo {
+    fn set_profile(s: u32) -> Weight {
+        (30_000_000 as Weight)
+            .saturating_add((4_000_000 as Weight).saturating_mul(s as Weight))
+            .saturating_add(DbWeight::get().reads(2 as Weight))
+            .saturating_add(DbWeight::get().reads((1 as Weight).saturating_mul(s as Weight)))
+            .saturating_add(DbWeight::get().writes(1 as Weight))
+            .saturating_add(DbWeight::get().writes((2 as Weight).saturating_mul(s as Weight)))
+    }
+    fn clear_profile() -> Weight {
+        (35_000_000 as Weight)
+            .saturating_add(DbWeight::get().reads(1 as Weight))
+            .saturating_add(DbWeight::get().writes(4 as Weight))
+    }
+    fn note_resolved() -> Weight {
+        (20_000_000 as Weight)
+            .saturating_add(DbWeight::get().reads(1 as Weight))
+            .saturating_add(DbWeight::get().writes(2 as Weight))
+    }
+}
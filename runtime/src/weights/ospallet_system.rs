@@ -0,0 +1,21 @@
+//! Weights for ospallet_system.
+//!
+//! These are estimated from the storage access of each call and have not been benchmarked yet,
+//! they must be replaced by the output of `scripts/benchmark.sh` on the reference hardware before
+//! a release.
+
+#![allow(unused_parens)]
+
+use frame_support::weights::{constants::RocksDbWeight as DbWeight, Weight};
+
+pub struct WeightInfo;
+impl ospallet_system::WeightInfo for WeightInfo {
+    fn modify_paused() -> Weight {
+        (25_000_000 as Weight)
+            .saturating_add(DbWeight::get().reads(1 as Weight))
+            .saturating_add(DbWeight::get().writes(1 as Weight))
+    }
+    fn modify_blocked_list() -> Weight {
+        (20_000_000 as Weight).saturating_add(DbWeight::get().writes(1 as Weight))
+    }
+}
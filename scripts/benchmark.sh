@@ -0,0 +1,15 @@
+#!/usr/bin/env bash
+
+set -e
+
+PALLETS=(ospallet_system ospallet_bounties ospallet_reputation ospallet_mining ospallet_profiles ospallet_badges)
+
+echo "*** Building the node with runtime benchmarks"
+WASM_BUILD_TOOLCHAIN=nightly-2020-10-06 cargo build --release --features runtime-benchmarks
+
+for pallet in "${PALLETS[@]}"; do
+   echo "*** Benchmarking $pallet"
+   ./target/release/opensquare benchmark --chain dev --execution wasm --wasm-execution compiled \
+      --pallet "$pallet" --extrinsic '*' --steps 50 --repeat 20 \
+      --output "runtime/src/weights/$pallet.rs"
+done